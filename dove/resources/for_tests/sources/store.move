@@ -0,0 +1,15 @@
+module Demo::Store {
+    struct U64 has key, store { val: u64 }
+
+    public(script) fun store_u64(account: signer, val: u64) {
+        move_to(&account, U64 { val });
+    }
+
+    public fun get_u64(addr: address): u64 acquires U64 {
+        borrow_global<U64>(addr).val
+    }
+
+    public fun pair(val: u64, flag: bool): (u64, bool) {
+        (val, flag)
+    }
+}
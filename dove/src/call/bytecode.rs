@@ -3,11 +3,23 @@ use std::path::{Path, PathBuf};
 use anyhow::Error;
 use move_binary_format::CompiledModule;
 use move_binary_format::file_format::{CompiledScript, empty_module};
+use move_bytecode_source_map::source_map::SourceMap;
+use move_core_types::language_storage::ModuleId;
 use move_package::compilation::package_layout::CompiledPackageLayout;
 use lang::bytecode::accessor::{Bytecode, BytecodeAccess, BytecodeRef, BytecodeType};
+use lang::bytecode::info::{BytecodeInfo, Field, Script, StructDef};
+use lang::bytecode::{find, SearchParams};
 use crate::context::Context;
 
+/// Struct layout resolver.
+pub trait StructResolver {
+    /// Returns fields of the given struct in declaration order.
+    /// Struct type parameters are substituted with the struct type arguments.
+    fn struct_fields(&self, def: &StructDef) -> Result<Vec<Field>, Error>;
+}
+
 /// Dove bytecode resolver.
+#[derive(Clone)]
 pub struct DoveBytecode {
     path: PathBuf,
 }
@@ -21,6 +33,88 @@ impl DoveBytecode {
     }
 }
 
+impl StructResolver for DoveBytecode {
+    fn struct_fields(&self, def: &StructDef) -> Result<Vec<Field>, Error> {
+        let st = find(
+            self.clone(),
+            SearchParams {
+                tp: Some(BytecodeType::Module),
+                package: None,
+                name: Some(def.module_name.as_str()),
+            },
+        )?
+        .filter_map(|info| info.ok())
+        .filter(|info| info.address() == Some(def.address) && info.name() == def.module_name)
+        .find_map(|info| info.find_struct(&def.name))
+        .ok_or_else(|| {
+            anyhow!(
+                "Struct {}::{}::{} not found in the project bytecode.",
+                def.address,
+                def.module_name,
+                def.name
+            )
+        })?;
+
+        Ok(st
+            .fields
+            .into_iter()
+            .map(|field| Field {
+                tp: field.tp.instantiate(&def.type_parameters),
+                name: field.name,
+            })
+            .collect())
+    }
+}
+
+/// Finds the module bytecode in the project build directory.
+pub fn find_module(ctx: &Context, module_id: &ModuleId) -> Result<Option<BytecodeInfo>, Error> {
+    Ok(find(
+        DoveBytecode::new(ctx),
+        SearchParams {
+            tp: Some(BytecodeType::Module),
+            package: None,
+            name: Some(module_id.name().as_str()),
+        },
+    )?
+    .filter_map(|bi| bi.ok())
+    .find(|bi| {
+        bi.address() == Some(*module_id.address()) && bi.name() == module_id.name().as_str()
+    }))
+}
+
+/// Loads the source map of the given bytecode from the `source_maps` folder of its package.
+pub fn load_source_map(bi: &BytecodeInfo) -> Result<SourceMap, Error> {
+    let path = PathBuf::from(&bi.bytecode_ref().0);
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| anyhow!("Failed to get file name:{:?}", path))?;
+    let package = path
+        .parent()
+        .and_then(|p| p.parent())
+        .ok_or_else(|| anyhow!("Failed to get package dir:{:?}", path))?;
+    let mut source_map = package
+        .join(CompiledPackageLayout::SourceMaps.path())
+        .join(name);
+    source_map.set_extension("mvsm");
+    Ok(bcs::from_bytes(&fs::read(source_map)?)?)
+}
+
+/// Returns source names of the function parameters.
+pub fn parameter_names(bi: &BytecodeInfo, func: &Script) -> Result<Vec<String>, Error> {
+    let source_map = load_source_map(bi)?;
+    let func_map = source_map
+        .function_map
+        .get(&func.index)
+        .ok_or_else(|| anyhow!("Source map of function {} not found", func.name))?;
+    Ok(func_map
+        .parameters
+        .iter()
+        // The compiler may add a suffix to local names: `name#0#0`.
+        .map(|(name, _)| name.split('#').next().unwrap_or_default().to_string())
+        .collect())
+}
+
 impl BytecodeAccess for DoveBytecode {
     fn list<'a>(
         &self,
@@ -2,7 +2,9 @@ use anyhow::Error;
 use clap::Parser;
 use std::fmt::Debug;
 use std::convert::TryFrom;
-use std::mem;
+use std::{fs, mem};
+use std::path::PathBuf;
+use serde_json::Value;
 use move_package::source_package::parsed_manifest::AddressDeclarations;
 use crate::call::parser::{parse_call, Call, parse_tp_param};
 
@@ -14,7 +16,9 @@ Examples:
       'script_name()'
       'Module::function()'
       'ALIAS_ADDRESSES::Module::function()'
+      'Module::function([[1, 2], [3]], {10, [true, false]}, 100000000000000000000000000000000000000)'
       '0x1::Module::function' --args [10,10] true ALIAS_ADDRESSES 100 0x1 --type 0x01::Dfinance::USD
+      'Module::function' --args-file args.json
       "#)]
     call: String,
     #[clap(
@@ -33,6 +37,14 @@ Examples:
         multiple_values = true
     )]
     params: Option<Vec<String>>,
+    #[clap(
+        help = r#"Path to a JSON file with script arguments: an array of values in parameter order
+or an object with parameter names as keys. Signers can still be passed with the call"#,
+        name = "Script arguments file.",
+        long = "args-file",
+        conflicts_with = "Script arguments."
+    )]
+    args_file: Option<PathBuf>,
     #[clap(
         help = r#"Move package name"#,
         name = "Move package name.",
@@ -43,6 +55,17 @@ Examples:
 }
 
 impl CallDeclarationCmd {
+    /// Creates a call declaration without extra arguments.
+    pub fn new(call: String, package: Option<String>) -> Self {
+        Self {
+            call,
+            type_parameters: None,
+            params: None,
+            args_file: None,
+            package,
+        }
+    }
+
     /// Takes call data.
     #[must_use]
     pub fn take(&mut self) -> Self {
@@ -50,6 +73,7 @@ impl CallDeclarationCmd {
             call: mem::take(&mut self.call),
             type_parameters: self.type_parameters.take(),
             params: self.params.take(),
+            args_file: self.args_file.take(),
             package: self.package.take(),
         }
     }
@@ -74,8 +98,19 @@ impl TryFrom<(&AddressDeclarations, CallDeclarationCmd)> for CallDeclaration {
             );
         }
 
+        let json_args = cmd
+            .args_file
+            .map(|path| {
+                let content = fs::read_to_string(&path)
+                    .map_err(|err| anyhow!("Failed to read args file {:?}: {}", path, err))?;
+                serde_json::from_str::<Value>(&content)
+                    .map_err(|err| anyhow!("Failed to parse args file {:?}: {}", path, err))
+            })
+            .transpose()?;
+
         Ok(CallDeclaration {
             call,
+            json_args,
             package: cmd.package,
         })
     }
@@ -85,6 +120,8 @@ impl TryFrom<(&AddressDeclarations, CallDeclarationCmd)> for CallDeclaration {
 pub struct CallDeclaration {
     /// Call declaration.
     pub call: Call,
+    /// Arguments from the JSON file.
+    pub json_args: Option<Value>,
     /// Package
     pub package: Option<String>,
 }
@@ -0,0 +1,268 @@
+use anyhow::Error;
+use serde_json::{Map, Value};
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::value::{MoveStructLayout, MoveTypeLayout, MoveValue};
+use lang::bytecode::info::Type;
+use crate::call::bytecode::StructResolver;
+use crate::call::fn_call::is_u256;
+use crate::natives::u256::U256;
+
+/// Returns runtime layout of the given type.
+pub fn type_layout(tp: &Type, structs: &dyn StructResolver) -> Result<MoveTypeLayout, Error> {
+    Ok(match tp {
+        Type::Bool => MoveTypeLayout::Bool,
+        Type::U8 => MoveTypeLayout::U8,
+        Type::U64 => MoveTypeLayout::U64,
+        Type::U128 => MoveTypeLayout::U128,
+        Type::Address => MoveTypeLayout::Address,
+        Type::Signer => MoveTypeLayout::Signer,
+        Type::Vector(tp) => MoveTypeLayout::Vector(Box::new(type_layout(tp, structs)?)),
+        Type::Struct(def) => MoveTypeLayout::Struct(MoveStructLayout::new(
+            structs
+                .struct_fields(def)?
+                .iter()
+                .map(|field| type_layout(&field.tp, structs))
+                .collect::<Result<_, Error>>()?,
+        )),
+        Type::Reference(_) | Type::MutableReference(_) | Type::TypeParameter(_) => {
+            bail!("Type {} has no runtime layout", tp)
+        }
+    })
+}
+
+/// Returns type tag of the given type.
+pub fn type_tag(tp: &Type) -> Result<TypeTag, Error> {
+    Ok(match tp {
+        Type::Bool => TypeTag::Bool,
+        Type::U8 => TypeTag::U8,
+        Type::U64 => TypeTag::U64,
+        Type::U128 => TypeTag::U128,
+        Type::Address => TypeTag::Address,
+        Type::Signer => TypeTag::Signer,
+        Type::Vector(tp) => TypeTag::Vector(Box::new(type_tag(tp)?)),
+        Type::Struct(def) => TypeTag::Struct(StructTag {
+            address: def.address,
+            module: Identifier::new(def.module_name.as_str())?,
+            name: Identifier::new(def.name.as_str())?,
+            type_params: def
+                .type_parameters
+                .iter()
+                .map(type_tag)
+                .collect::<Result<_, Error>>()?,
+        }),
+        Type::Reference(_) | Type::MutableReference(_) | Type::TypeParameter(_) => {
+            bail!("Type {} has no type tag", tp)
+        }
+    })
+}
+
+/// Decodes BCS-encoded value of the given type.
+pub fn decode_value(
+    tp: &Type,
+    blob: &[u8],
+    structs: &dyn StructResolver,
+) -> Result<MoveValue, Error> {
+    let layout = type_layout(tp, structs)?;
+    MoveValue::simple_deserialize(blob, &layout)
+        .map_err(|err| anyhow!("Failed to decode value of type {}: {}", tp, err))
+}
+
+/// Renders the value in the same format that is accepted by the arguments file.
+/// `u128` and `U256` numbers are rendered as strings, `vector<u8>` as a hex string
+/// and structs as objects with field names as keys.
+pub fn value_to_json(
+    tp: &Type,
+    value: MoveValue,
+    structs: &dyn StructResolver,
+) -> Result<Value, Error> {
+    Ok(match (tp, value) {
+        (Type::Bool, MoveValue::Bool(val)) => Value::Bool(val),
+        (Type::U8, MoveValue::U8(val)) => Value::from(val),
+        (Type::U64, MoveValue::U64(val)) => Value::from(val),
+        (Type::U128, MoveValue::U128(val)) => Value::String(val.to_string()),
+        (Type::Address, MoveValue::Address(val)) | (Type::Signer, MoveValue::Signer(val)) => {
+            Value::String(val.to_hex_literal())
+        }
+        (Type::Vector(inner), MoveValue::Vector(items)) => {
+            if **inner == Type::U8 {
+                Value::String(format!("0x{}", hex::encode(bytes(items)?)))
+            } else {
+                Value::Array(
+                    items
+                        .into_iter()
+                        .map(|item| value_to_json(inner, item, structs))
+                        .collect::<Result<_, Error>>()?,
+                )
+            }
+        }
+        (Type::Struct(def), MoveValue::Struct(val)) if is_u256(def) => {
+            Value::String(u256(val.into_fields())?.to_string())
+        }
+        (Type::Struct(def), MoveValue::Struct(val)) => {
+            let mut fields = Map::new();
+            for (field, val) in structs.struct_fields(def)?.iter().zip(val.into_fields()) {
+                fields.insert(field.name.clone(), value_to_json(&field.tp, val, structs)?);
+            }
+            Value::Object(fields)
+        }
+        (tp, value) => bail!("Unexpected value {:?} of type {}", value, tp),
+    })
+}
+
+/// Renders the value in Move-like text format.
+pub fn value_to_string(
+    tp: &Type,
+    value: MoveValue,
+    structs: &dyn StructResolver,
+) -> Result<String, Error> {
+    Ok(match (tp, value) {
+        (Type::Bool, MoveValue::Bool(val)) => val.to_string(),
+        (Type::U8, MoveValue::U8(val)) => format!("{}u8", val),
+        (Type::U64, MoveValue::U64(val)) => val.to_string(),
+        (Type::U128, MoveValue::U128(val)) => format!("{}u128", val),
+        (Type::Address, MoveValue::Address(val)) | (Type::Signer, MoveValue::Signer(val)) => {
+            val.to_hex_literal()
+        }
+        (Type::Vector(inner), MoveValue::Vector(items)) => {
+            if **inner == Type::U8 {
+                format!("x\"{}\"", hex::encode(bytes(items)?))
+            } else {
+                let items = items
+                    .into_iter()
+                    .map(|item| value_to_string(inner, item, structs))
+                    .collect::<Result<Vec<_>, Error>>()?;
+                format!("[{}]", items.join(", "))
+            }
+        }
+        (Type::Struct(def), MoveValue::Struct(val)) if is_u256(def) => {
+            format!("{}u256", u256(val.into_fields())?)
+        }
+        (Type::Struct(def), MoveValue::Struct(val)) => {
+            let fields = structs
+                .struct_fields(def)?
+                .iter()
+                .zip(val.into_fields())
+                .map(|(field, val)| {
+                    value_to_string(&field.tp, val, structs)
+                        .map(|val| format!("{}: {}", field.name, val))
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            format!(
+                "{}::{} {{ {} }}",
+                def.module_name,
+                def.name,
+                fields.join(", ")
+            )
+        }
+        (tp, value) => bail!("Unexpected value {:?} of type {}", value, tp),
+    })
+}
+
+fn bytes(items: Vec<MoveValue>) -> Result<Vec<u8>, Error> {
+    items
+        .into_iter()
+        .map(|item| match item {
+            MoveValue::U8(val) => Ok(val),
+            _ => bail!("Unexpected vector<u8> item {:?}", item),
+        })
+        .collect()
+}
+
+fn u256(mut fields: Vec<MoveValue>) -> Result<U256, Error> {
+    match fields.pop() {
+        Some(MoveValue::Vector(items)) if fields.is_empty() => {
+            let bytes = bytes(items)?;
+            ensure!(bytes.len() == 32, "Invalid U256 length {}", bytes.len());
+            Ok(U256::from_little_endian(&bytes))
+        }
+        _ => bail!("Invalid U256 value"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+    use serde_json::json;
+    use move_core_types::language_storage::CORE_CODE_ADDRESS;
+    use lang::bytecode::info::{Field, StructDef, Type};
+    use crate::call::bytecode::StructResolver;
+    use crate::call::decode::{decode_value, value_to_json, value_to_string};
+    use crate::call::model::{ScriptArg, Transaction};
+
+    /// Resolves `0x1::Pair::Pair` as `{ a: u64, b: vector<u8> }`.
+    struct Structs;
+
+    impl StructResolver for Structs {
+        fn struct_fields(&self, def: &StructDef) -> Result<Vec<Field>, Error> {
+            if def.module_name == "U256" {
+                return Ok(vec![Field {
+                    name: "v".to_string(),
+                    tp: Type::Vector(Box::new(Type::U8)),
+                }]);
+            }
+            ensure!(def.name == "Pair", "Unknown struct {}", def.name);
+            Ok(vec![
+                Field {
+                    name: "a".to_string(),
+                    tp: Type::U64,
+                },
+                Field {
+                    name: "b".to_string(),
+                    tp: Type::Vector(Box::new(Type::U8)),
+                },
+            ])
+        }
+    }
+
+    fn struct_tp(module_name: &str, name: &str) -> Type {
+        Type::Struct(StructDef {
+            address: CORE_CODE_ADDRESS,
+            module_name: module_name.to_string(),
+            name: name.to_string(),
+            type_parameters: vec![],
+        })
+    }
+
+    #[test]
+    fn test_decode_args() {
+        let pairs = Type::Vector(Box::new(struct_tp("Pair", "Pair")));
+        let u256 = struct_tp("U256", "U256");
+        let mut u256_bytes = vec![0; 32];
+        u256_bytes[16] = 1;
+
+        let args = Transaction::make_args(vec![
+            ScriptArg::Vector(vec![ScriptArg::Struct(vec![
+                ScriptArg::U64(1),
+                ScriptArg::VectorU8(vec![0xca, 0xfe]),
+            ])]),
+            ScriptArg::Struct(vec![ScriptArg::VectorU8(u256_bytes)]),
+            ScriptArg::U128(10),
+        ])
+        .unwrap();
+
+        let value = decode_value(&pairs, &args[0], &Structs).unwrap();
+        assert_eq!(
+            value_to_json(&pairs, value.clone(), &Structs).unwrap(),
+            json!([{"a": 1, "b": "0xcafe"}])
+        );
+        assert_eq!(
+            value_to_string(&pairs, value, &Structs).unwrap(),
+            "[Pair::Pair { a: 1, b: x\"cafe\" }]"
+        );
+
+        let value = decode_value(&u256, &args[1], &Structs).unwrap();
+        assert_eq!(
+            value_to_json(&u256, value, &Structs).unwrap(),
+            json!("340282366920938463463374607431768211456")
+        );
+
+        let value = decode_value(&Type::U128, &args[2], &Structs).unwrap();
+        assert_eq!(
+            value_to_string(&Type::U128, value, &Structs).unwrap(),
+            "10u128"
+        );
+
+        decode_value(&Type::U64, &args[2], &Structs).unwrap_err();
+    }
+}
@@ -1,6 +1,7 @@
 use std::str::FromStr;
 use std::fmt::Debug;
 use anyhow::{Error, Result};
+use serde_json::Value;
 use move_symbol_pool::Symbol;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
@@ -8,11 +9,13 @@ use move_core_types::language_storage::{CORE_CODE_ADDRESS, TypeTag};
 use move_package::source_package::parsed_manifest::AddressDeclarations;
 use lang::bytecode::accessor::BytecodeType;
 use lang::bytecode::{find, SearchParams};
-use lang::bytecode::info::{BytecodeInfo, Type};
+use lang::bytecode::info::{BytecodeInfo, StructDef, Type};
 use crate::context::Context;
 use crate::call::model::{Signer, ScriptArg, Transaction, Signers, EnrichedTransaction, Call};
-use crate::call::parser::parse_vec;
-use crate::call::bytecode::DoveBytecode;
+use crate::call::parser::{parse_vec, split_items};
+use crate::call::bytecode::{DoveBytecode, StructResolver, parameter_names};
+use crate::call::json::prepare_json_args;
+use crate::natives::u256::U256;
 
 fn diem_root_address() -> AccountAddress {
     AccountAddress::from_hex_literal("0xA550C18")
@@ -25,6 +28,10 @@ pub struct Config {
     tx_context: bool,
     /// Prohibit the definition of signers.
     deny_signers_definition: bool,
+    /// Allow calls of public functions, not only script ones.
+    allow_public_functions: bool,
+    /// Signer used when the call omits signers.
+    default_signer: Option<AccountAddress>,
 }
 
 impl Config {
@@ -33,6 +40,8 @@ impl Config {
         Config {
             tx_context: true,
             deny_signers_definition: true,
+            allow_public_functions: false,
+            default_signer: None,
         }
     }
 
@@ -41,20 +50,31 @@ impl Config {
         Config {
             tx_context: false,
             deny_signers_definition: false,
+            allow_public_functions: true,
+            default_signer: None,
         }
     }
+
+    /// Sets the signer used when the call omits signers.
+    pub fn with_default_signer(mut self, signer: Option<AccountAddress>) -> Config {
+        self.default_signer = signer;
+        self
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn make_script_call(
     ctx: &Context,
     addr_map: &AddressDeclarations,
     name: Identifier,
     type_tag: Vec<TypeTag>,
     args: Vec<String>,
+    json_args: Option<&Value>,
     package_name: Option<String>,
     cfg: Config,
 ) -> Result<EnrichedTransaction, Error> {
     let access = DoveBytecode::new(ctx);
+    let structs = access.clone();
     let functions = find(
         access,
         SearchParams {
@@ -64,8 +84,9 @@ pub(crate) fn make_script_call(
         },
     )?
     .filter_map(|f| f.ok());
-    let (signers, args, info) =
-        select_function(functions, &name, &args, &type_tag, &cfg, addr_map)?;
+    let (signers, args, info) = select_function(
+        functions, &name, &args, json_args, &type_tag, &cfg, addr_map, &structs,
+    )?;
 
     Ok(if cfg.tx_context {
         let (_, mut tx) = match signers {
@@ -118,10 +139,12 @@ pub(crate) fn make_function_call(
     func: Identifier,
     type_tag: Vec<TypeTag>,
     args: Vec<String>,
+    json_args: Option<&Value>,
     package_name: Option<String>,
     cfg: Config,
 ) -> Result<EnrichedTransaction, Error> {
     let access = DoveBytecode::new(ctx);
+    let structs = access.clone();
     let modules = find(
         access,
         SearchParams {
@@ -139,8 +162,9 @@ pub(crate) fn make_function_call(
         }
     })
     .filter(|info| info.name() == module.as_str());
-    let (signers, args, info) =
-        select_function(modules, &func, &args, &type_tag, &cfg, addr_map)?;
+    let (signers, args, info) = select_function(
+        modules, &func, &args, json_args, &type_tag, &cfg, addr_map, &structs,
+    )?;
 
     let addr = info.address().unwrap_or(CORE_CODE_ADDRESS);
     let tx_name = format!("{}_{}", module, func);
@@ -175,27 +199,53 @@ pub(crate) fn make_function_call(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn select_function<I>(
     info_iter: I,
     name: &Identifier,
     args: &[String],
+    json_args: Option<&Value>,
     type_tag: &[TypeTag],
     cfg: &Config,
     addr_map: &AddressDeclarations,
+    structs: &dyn StructResolver,
 ) -> Result<(Signers, Vec<ScriptArg>, BytecodeInfo), Error>
 where
     I: Iterator<Item = BytecodeInfo>,
 {
     let mut functions = info_iter
-        .filter_map(|info| info.find_script_function(name.as_str()).map(|f| (info, f)))
+        .filter_map(|info| {
+            if cfg.allow_public_functions {
+                info.find_public_function(name.as_str())
+            } else {
+                info.find_script_function(name.as_str())
+            }
+            .map(|f| (info, f))
+        })
         .filter(|(_, f)| type_tag.len() == f.type_params_count())
         .map(|(i, script)| {
-            prepare_function_signature(
-                &script.parameters,
-                args,
-                !cfg.deny_signers_definition,
-                addr_map,
-            )
+            let args = with_default_signers(&script.parameters, args, json_args.is_some(), cfg);
+            match json_args {
+                Some(json_args) => {
+                    let names = parameter_names(&i, &script).ok();
+                    prepare_json_signature(
+                        &script.parameters,
+                        names.as_deref(),
+                        &args,
+                        json_args,
+                        !cfg.deny_signers_definition,
+                        addr_map,
+                        structs,
+                    )
+                }
+                None => prepare_function_signature(
+                    &script.parameters,
+                    &args,
+                    !cfg.deny_signers_definition,
+                    addr_map,
+                    structs,
+                ),
+            }
             .map(|(signers, args)| (i, script, signers, args))
         })
         .collect::<Vec<Result<_, _>>>();
@@ -221,11 +271,39 @@ where
     }
 }
 
+/// Prepends the default signer to the call arguments if the call omits signers.
+fn with_default_signers(
+    code_args: &[Type],
+    call_args: &[String],
+    json_args: bool,
+    cfg: &Config,
+) -> Vec<String> {
+    let signers_count = code_args
+        .iter()
+        .take_while(|tp| **tp == Type::Signer)
+        .count();
+    let omitted = if json_args {
+        call_args.is_empty()
+    } else {
+        call_args.len() == code_args.len() - signers_count
+    };
+    match cfg.default_signer {
+        Some(signer) if signers_count > 0 && omitted => {
+            std::iter::repeat(signer.to_hex_literal())
+                .take(signers_count)
+                .chain(call_args.iter().cloned())
+                .collect()
+        }
+        _ => call_args.to_vec(),
+    }
+}
+
 fn prepare_function_signature(
     code_args: &[Type],
     call_args: &[String],
     use_explicit_signers: bool,
     addr_map: &AddressDeclarations,
+    structs: &dyn StructResolver,
 ) -> Result<(Signers, Vec<ScriptArg>), Error> {
     let signers_count = code_args
         .iter()
@@ -245,11 +323,58 @@ fn prepare_function_signature(
     let params = code_args[signers_count..]
         .iter()
         .zip(&call_args[args_index..])
-        .map(|(tp, val)| prepare_arg(tp, val, addr_map))
+        .map(|(tp, val)| prepare_arg(tp, val, addr_map, structs))
         .collect::<Result<Vec<_>, Error>>()?;
 
+    let signers = prepare_signers(
+        signers_count,
+        &call_args[..args_index],
+        use_explicit_signers,
+        addr_map,
+    )?;
+    Ok((signers, params))
+}
+
+/// Prepares a function signature with the arguments passed as JSON.
+/// Only signers can be passed in `call_args`.
+fn prepare_json_signature(
+    code_args: &[Type],
+    param_names: Option<&[String]>,
+    call_args: &[String],
+    json_args: &Value,
+    use_explicit_signers: bool,
+    addr_map: &AddressDeclarations,
+    structs: &dyn StructResolver,
+) -> Result<(Signers, Vec<ScriptArg>), Error> {
+    let signers_count = code_args
+        .iter()
+        .take_while(|tp| **tp == Type::Signer)
+        .count();
+    ensure!(
+        call_args.len() <= signers_count,
+        "Only signers can be passed with the call when the arguments file is used"
+    );
+
+    let names = param_names.map(|names| &names[signers_count.min(names.len())..]);
+    let params = prepare_json_args(
+        &code_args[signers_count..],
+        names,
+        json_args,
+        addr_map,
+        structs,
+    )?;
+    let signers = prepare_signers(signers_count, call_args, use_explicit_signers, addr_map)?;
+    Ok((signers, params))
+}
+
+fn prepare_signers(
+    signers_count: usize,
+    signer_args: &[String],
+    use_explicit_signers: bool,
+    addr_map: &AddressDeclarations,
+) -> Result<Signers, Error> {
     if use_explicit_signers {
-        let signers = call_args[..args_index]
+        let signers = signer_args
             .iter()
             .map(|arg| {
                 if arg.starts_with("0x") {
@@ -281,11 +406,12 @@ fn prepare_function_signature(
             signers_count,
             signers.len()
         );
-        Ok((Signers::Explicit(signers), params))
+        Ok(Signers::Explicit(signers))
     } else {
-        let mut signers = (0..signers_count)
-            .take_while(|i| *i < args_index)
-            .map(|i| Signer::from_str(&call_args[i]).ok())
+        let mut signers = signer_args
+            .iter()
+            .take(signers_count)
+            .map(|arg| Signer::from_str(arg).ok())
             .take_while(|s| s.is_some())
             .flatten()
             .collect::<Vec<_>>();
@@ -295,7 +421,7 @@ fn prepare_function_signature(
             signers.push(Signer::Placeholder);
         }
 
-        Ok((Signers::Implicit(signers), params))
+        Ok(Signers::Implicit(signers))
     }
 }
 
@@ -303,6 +429,7 @@ fn prepare_arg(
     arg_type: &Type,
     arg_value: &str,
     addr_map: &AddressDeclarations,
+    structs: &dyn StructResolver,
 ) -> Result<ScriptArg, Error> {
     macro_rules! parse_primitive {
         ($script_arg:expr) => {
@@ -334,7 +461,8 @@ fn prepare_arg(
                 parse_vec(arg_value, "u64").map_err(|err| parse_err(arg_type, arg_value, err))?,
             ),
             Type::U128 => ScriptArg::VectorU128(
-                parse_vec(arg_value, "u64").map_err(|err| parse_err(arg_type, arg_value, err))?,
+                parse_vec(arg_value, "u128")
+                    .map_err(|err| parse_err(arg_type, arg_value, err))?,
             ),
             Type::Address => {
                 let addresses = parse_vec::<String>(arg_value, "vector<address>")
@@ -344,23 +472,69 @@ fn prepare_arg(
                     .collect::<Result<Vec<_>, Error>>()?;
                 ScriptArg::VectorAddress(addresses)
             }
+            Type::Vector(_) | Type::Struct(_) => ScriptArg::Vector(
+                split_items(arg_value, '[', ']')
+                    .map_err(|err| parse_err(arg_type, arg_value, err))?
+                    .iter()
+                    .map(|item| prepare_arg(tp, item, addr_map, structs))
+                    .collect::<Result<_, Error>>()?,
+            ),
             Type::Signer
-            | Type::Vector(_)
-            | Type::Struct(_)
             | Type::Reference(_)
             | Type::MutableReference(_)
             | Type::TypeParameter(_) => {
                 anyhow::bail!("Unexpected script parameter: {:?}", arg_type)
             }
         },
+        Type::Struct(def) if is_u256(def) => parse_u256(arg_value)?,
+        Type::Struct(def) => {
+            let fields = structs.struct_fields(def)?;
+            let values = split_items(arg_value, '{', '}')
+                .map_err(|err| parse_err(arg_type, arg_value, err))?;
+            ensure!(
+                fields.len() == values.len(),
+                "Struct {}::{} has {} fields, {} are passed: {}",
+                def.module_name,
+                def.name,
+                fields.len(),
+                values.len(),
+                arg_value
+            );
+            ScriptArg::Struct(
+                fields
+                    .iter()
+                    .zip(&values)
+                    .map(|(field, val)| prepare_arg(&field.tp, val, addr_map, structs))
+                    .collect::<Result<_, Error>>()?,
+            )
+        }
         Type::Signer
-        | Type::Struct(_)
         | Type::Reference(_)
         | Type::MutableReference(_)
-        | Type::TypeParameter(_) => anyhow::bail!("Unexpected script parameter: {:?}", arg_type),
+        | Type::TypeParameter(_) => {
+            anyhow::bail!("Unexpected script parameter: {:?}", arg_type)
+        }
     })
 }
 
+/// Returns `true` if the struct is `0x1::U256::U256` from the pontem standard library.
+pub(crate) fn is_u256(def: &StructDef) -> bool {
+    def.address == CORE_CODE_ADDRESS && def.module_name == "U256" && def.name == "U256"
+}
+
+/// Parses decimal or `0x`-prefixed hexadecimal number as `0x1::U256::U256` value.
+pub(crate) fn parse_u256(arg_value: &str) -> Result<ScriptArg, Error> {
+    let value = if let Some(hex) = arg_value.strip_prefix("0x") {
+        U256::from_str_radix(hex, 16)
+    } else {
+        U256::from_dec_str(arg_value)
+    }
+    .map_err(|err| anyhow!("Failed to parse {} as U256. Error:'{:?}'", arg_value, err))?;
+    let mut bytes = vec![0; 32];
+    value.to_little_endian(&mut bytes);
+    Ok(ScriptArg::Struct(vec![ScriptArg::VectorU8(bytes)]))
+}
+
 fn pontem_parse_address(addr: &str) -> Result<AccountAddress> {
     if let Ok(address) = pontem::ss58_to_address(addr) {
         // first try ss58 parsing
@@ -385,7 +559,7 @@ fn pontem_parse_address(addr: &str) -> Result<AccountAddress> {
     }
 }
 
-fn parse_address(
+pub(crate) fn parse_address(
     arg_value: &str,
     addr_map: &AddressDeclarations,
 ) -> Result<AccountAddress, Error> {
@@ -409,16 +583,53 @@ fn parse_err<D: Debug>(tp: &Type, value: &str, err: D) -> Error {
 
 #[cfg(test)]
 mod call_tests {
+    use anyhow::Error;
     use move_core_types::language_storage::CORE_CODE_ADDRESS;
     use move_core_types::account_address::AccountAddress;
-    use lang::bytecode::info::Type;
-    use crate::call::model::ScriptArg;
-    use crate::call::fn_call::prepare_function_signature;
+    use lang::bytecode::info::{Field, StructDef, Type};
+    use crate::call::bytecode::StructResolver;
+    use crate::call::model::{ScriptArg, Signers};
+    use crate::call::fn_call::{
+        prepare_function_signature, prepare_json_signature, with_default_signers, Config,
+    };
+    use serde_json::json;
+
+    /// Resolves `0x1::Pair::Pair<T>` as `{ a: u64, b: vector<T> }`.
+    struct Structs;
+
+    impl StructResolver for Structs {
+        fn struct_fields(&self, def: &StructDef) -> Result<Vec<Field>, Error> {
+            if def.module_name == "Pair" && def.name == "Pair" {
+                Ok(vec![
+                    Field {
+                        name: "a".to_string(),
+                        tp: Type::U64,
+                    },
+                    Field {
+                        name: "b".to_string(),
+                        tp: Type::Vector(Box::new(Type::TypeParameter(0)))
+                            .instantiate(&def.type_parameters),
+                    },
+                ])
+            } else {
+                bail!("Unknown struct {}", def.name)
+            }
+        }
+    }
 
     fn s(v: &str) -> String {
         v.to_string()
     }
 
+    fn struct_tp(module_name: &str, name: &str, type_parameters: Vec<Type>) -> Type {
+        Type::Struct(StructDef {
+            address: CORE_CODE_ADDRESS,
+            module_name: module_name.to_string(),
+            name: name.to_string(),
+            type_parameters,
+        })
+    }
+
     fn addr(v: &str) -> AccountAddress {
         AccountAddress::from_hex_literal(v).unwrap()
     }
@@ -426,13 +637,18 @@ mod call_tests {
     #[test]
     fn test_args_types() {
         let (signers, args) =
-            prepare_function_signature(&[], &[], true, &Default::default()).unwrap();
+            prepare_function_signature(&[], &[], true, &Default::default(), &Structs).unwrap();
         assert_eq!(signers.len(), 0);
         assert_eq!(args.len(), 0);
 
-        let (signers, args) =
-            prepare_function_signature(&[Type::U8], &[s("1")], true, &Default::default())
-                .unwrap();
+        let (signers, args) = prepare_function_signature(
+            &[Type::U8],
+            &[s("1")],
+            true,
+            &Default::default(),
+            &Structs,
+        )
+        .unwrap();
         assert_eq!(signers.len(), 0);
         assert_eq!(args, vec![ScriptArg::U8(1)]);
 
@@ -441,6 +657,7 @@ mod call_tests {
             &[s("true"), s("false")],
             true,
             &Default::default(),
+            &Structs,
         )
         .unwrap();
         assert_eq!(signers.len(), 0);
@@ -451,6 +668,7 @@ mod call_tests {
             &[s("0"), s("1000000000"), s("10000000000000000")],
             true,
             &Default::default(),
+            &Structs,
         )
         .unwrap();
         assert_eq!(signers.len(), 0);
@@ -463,9 +681,14 @@ mod call_tests {
             ]
         );
 
-        let (signers, args) =
-            prepare_function_signature(&[Type::Address], &[s("0x1")], true, &Default::default())
-                .unwrap();
+        let (signers, args) = prepare_function_signature(
+            &[Type::Address],
+            &[s("0x1")],
+            true,
+            &Default::default(),
+            &Structs,
+        )
+        .unwrap();
         assert_eq!(signers.len(), 0);
         assert_eq!(args, vec![ScriptArg::Address(CORE_CODE_ADDRESS)]);
 
@@ -490,6 +713,7 @@ mod call_tests {
             ],
             true,
             &Default::default(),
+            &Structs,
         )
         .unwrap();
         assert_eq!(signers.len(), 0);
@@ -506,4 +730,193 @@ mod call_tests {
             ]
         );
     }
+
+    #[test]
+    fn test_nested_args() {
+        let (_, args) = prepare_function_signature(
+            &[
+                Type::Vector(Box::new(Type::Vector(Box::new(Type::U8)))),
+                Type::Vector(Box::new(Type::Vector(Box::new(Type::Bool)))),
+            ],
+            &[s("[[1, 2], 0304, []]"), s("[[true],[]]")],
+            true,
+            &Default::default(),
+            &Structs,
+        )
+        .unwrap();
+        assert_eq!(
+            args,
+            vec![
+                ScriptArg::Vector(vec![
+                    ScriptArg::VectorU8(vec![1, 2]),
+                    ScriptArg::VectorU8(vec![3, 4]),
+                    ScriptArg::VectorU8(vec![]),
+                ]),
+                ScriptArg::Vector(vec![
+                    ScriptArg::VectorBool(vec![true]),
+                    ScriptArg::VectorBool(vec![]),
+                ]),
+            ]
+        );
+
+        prepare_function_signature(
+            &[Type::Vector(Box::new(Type::Vector(Box::new(Type::U64))))],
+            &[s("[[1], [true]]")],
+            true,
+            &Default::default(),
+            &Structs,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_struct_args() {
+        let pair = struct_tp("Pair", "Pair", vec![Type::Address]);
+        let (_, args) = prepare_function_signature(
+            &[
+                pair.clone(),
+                Type::Vector(Box::new(pair.clone())),
+                struct_tp("U256", "U256", vec![]),
+            ],
+            &[
+                s("{1, [0x1, 0x2]}"),
+                s("[{2, []}]"),
+                s("340282366920938463463374607431768211456"),
+            ],
+            true,
+            &Default::default(),
+            &Structs,
+        )
+        .unwrap();
+        let mut u256 = vec![0; 32];
+        u256[16] = 1;
+        assert_eq!(
+            args,
+            vec![
+                ScriptArg::Struct(vec![
+                    ScriptArg::U64(1),
+                    ScriptArg::VectorAddress(vec![addr("0x1"), addr("0x2")]),
+                ]),
+                ScriptArg::Vector(vec![ScriptArg::Struct(vec![
+                    ScriptArg::U64(2),
+                    ScriptArg::VectorAddress(vec![]),
+                ])]),
+                ScriptArg::Struct(vec![ScriptArg::VectorU8(u256)]),
+            ]
+        );
+
+        prepare_function_signature(&[pair], &[s("{1}")], true, &Default::default(), &Structs)
+            .unwrap_err();
+        prepare_function_signature(
+            &[struct_tp("Coin", "Coin", vec![])],
+            &[s("{1}")],
+            true,
+            &Default::default(),
+            &Structs,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_json_args() {
+        let pair = struct_tp("Pair", "Pair", vec![Type::U8]);
+        let code_args = [
+            Type::Signer,
+            Type::U128,
+            Type::Vector(Box::new(Type::U8)),
+            Type::Vector(Box::new(pair.clone())),
+        ];
+        let names = [s("account"), s("amount"), s("data"), s("pairs")];
+        let expected = vec![
+            ScriptArg::U128(340282366920938463463374607431768211455),
+            ScriptArg::VectorU8(vec![0xca, 0xfe]),
+            ScriptArg::Vector(vec![ScriptArg::Struct(vec![
+                ScriptArg::U64(1),
+                ScriptArg::VectorU8(vec![2, 3]),
+            ])]),
+        ];
+
+        let (signers, args) = prepare_json_signature(
+            &code_args,
+            None,
+            &[s("0x1")],
+            &json!([
+                "340282366920938463463374607431768211455",
+                "0xcafe",
+                [[1, [2, 3]]]
+            ]),
+            true,
+            &Default::default(),
+            &Structs,
+        )
+        .unwrap();
+        assert_eq!(signers, Signers::Explicit(vec![addr("0x1")]));
+        assert_eq!(args, expected);
+
+        let (_, args) = prepare_json_signature(
+            &code_args,
+            Some(&names[..]),
+            &[],
+            &json!({
+                "amount": "340282366920938463463374607431768211455",
+                "data": [202, 254],
+                "pairs": [{"a": 1, "b": [2, 3]}]
+            }),
+            false,
+            &Default::default(),
+            &Structs,
+        )
+        .unwrap();
+        assert_eq!(args, expected);
+    }
+
+    #[test]
+    fn test_json_args_errors() {
+        let pair = struct_tp("Pair", "Pair", vec![Type::U8]);
+        let code_args = [Type::U64, Type::Vector(Box::new(pair))];
+        let names = [s("amount"), s("pairs")];
+        let err = |args: serde_json::Value| {
+            prepare_json_signature(
+                &code_args,
+                Some(&names[..]),
+                &[],
+                &args,
+                false,
+                &Default::default(),
+                &Structs,
+            )
+            .unwrap_err()
+            .to_string()
+        };
+
+        assert!(err(json!([1])).contains("accepts 2 parameters, 1 are passed"));
+        assert!(err(json!({"amount": 1})).starts_with("$.pairs: parameter is missing"));
+        assert!(err(json!({"amount": 1, "pairs": [], "fee": 1})).starts_with("$.fee: unknown"));
+        assert!(err(json!([-1, []])).starts_with("$[0]: failed to parse -1 as u64"));
+        assert!(err(json!([1, [{"a": 1, "b": [256]}]])).starts_with("$[1][0].b[0]:"));
+        assert!(err(json!([1, [{"a": 1}]])).starts_with("$[1][0].b: field is missing"));
+        assert!(err(json!({"amount": true, "pairs": []})).starts_with("$.amount: u64"));
+    }
+
+    #[test]
+    fn test_default_signer() {
+        let code_args = [Type::Signer, Type::U64];
+        let cfg = Config::for_run().with_default_signer(Some(CORE_CODE_ADDRESS));
+        assert_eq!(
+            with_default_signers(&code_args, &[s("1")], false, &cfg),
+            vec![s("0x1"), s("1")]
+        );
+        assert_eq!(
+            with_default_signers(&code_args, &[s("0x2"), s("1")], false, &cfg),
+            vec![s("0x2"), s("1")]
+        );
+        assert_eq!(
+            with_default_signers(&code_args, &[], true, &cfg),
+            vec![s("0x1")]
+        );
+        assert_eq!(
+            with_default_signers(&code_args, &[s("1")], false, &Config::for_run()),
+            vec![s("1")]
+        );
+    }
 }
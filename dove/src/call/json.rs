@@ -0,0 +1,224 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use anyhow::Error;
+use serde_json::{Map, Value};
+use move_package::source_package::parsed_manifest::AddressDeclarations;
+use lang::bytecode::info::Type;
+use crate::call::bytecode::StructResolver;
+use crate::call::fn_call::{is_u256, parse_address, parse_u256};
+use crate::call::model::ScriptArg;
+
+/// Converts JSON arguments to script arguments.
+/// `args` is an array of values in parameter order or an object with parameter names as keys.
+/// `names` are the parameter names, they are required only for the object form.
+pub(crate) fn prepare_json_args(
+    types: &[Type],
+    names: Option<&[String]>,
+    args: &Value,
+    addr_map: &AddressDeclarations,
+    structs: &dyn StructResolver,
+) -> Result<Vec<ScriptArg>, Error> {
+    match args {
+        Value::Array(values) => {
+            ensure!(
+                values.len() == types.len(),
+                "The function accepts {} parameters, {} are passed",
+                types.len(),
+                values.len()
+            );
+            types
+                .iter()
+                .zip(values)
+                .enumerate()
+                .map(|(idx, (tp, val))| {
+                    prepare_json_arg(tp, val, &format!("$[{}]", idx), addr_map, structs)
+                })
+                .collect()
+        }
+        Value::Object(values) => {
+            let names = names.ok_or_else(|| {
+                anyhow!(
+                    "Function parameter names are not available. \
+                    Pass the arguments as a JSON array."
+                )
+            })?;
+            ensure_known_keys(values, names, "$", "parameter")?;
+            types
+                .iter()
+                .zip(names)
+                .map(|(tp, name)| {
+                    let path = format!("$.{}", name);
+                    let val = values
+                        .get(name)
+                        .ok_or_else(|| anyhow!("{}: parameter is missing", path))?;
+                    prepare_json_arg(tp, val, &path, addr_map, structs)
+                })
+                .collect()
+        }
+        _ => bail!("$: array or object with the function arguments is expected"),
+    }
+}
+
+/// Converts the JSON value of the given type to a script argument.
+/// `path` is the JSONPath of the value used in the error messages.
+pub(crate) fn prepare_json_arg(
+    tp: &Type,
+    value: &Value,
+    path: &str,
+    addr_map: &AddressDeclarations,
+    structs: &dyn StructResolver,
+) -> Result<ScriptArg, Error> {
+    Ok(match tp {
+        Type::Bool => ScriptArg::Bool(as_bool(value, path)?),
+        Type::U8 => ScriptArg::U8(as_number(value, path, tp)?),
+        Type::U64 => ScriptArg::U64(as_number(value, path, tp)?),
+        Type::U128 => ScriptArg::U128(as_number(value, path, tp)?),
+        Type::Address => ScriptArg::Address(as_address(value, path, addr_map)?),
+        Type::Vector(inner) => {
+            if let (Type::U8, Value::String(hex)) = (inner.as_ref(), value) {
+                return hex::decode(hex.trim_start_matches("0x"))
+                    .map(ScriptArg::VectorU8)
+                    .map_err(|err| anyhow!("{}: invalid hex string. {}", path, err));
+            }
+
+            let items = value.as_array().ok_or_else(|| type_err(path, tp, value))?;
+            match inner.as_ref() {
+                Type::Bool => ScriptArg::VectorBool(map_items(items, path, as_bool)?),
+                Type::U8 => {
+                    ScriptArg::VectorU8(map_items(items, path, |v, p| as_number(v, p, inner))?)
+                }
+                Type::U64 => {
+                    ScriptArg::VectorU64(map_items(items, path, |v, p| as_number(v, p, inner))?)
+                }
+                Type::U128 => {
+                    ScriptArg::VectorU128(map_items(items, path, |v, p| as_number(v, p, inner))?)
+                }
+                Type::Address => ScriptArg::VectorAddress(map_items(items, path, |v, p| {
+                    as_address(v, p, addr_map)
+                })?),
+                _ => ScriptArg::Vector(map_items(items, path, |v, p| {
+                    prepare_json_arg(inner, v, p, addr_map, structs)
+                })?),
+            }
+        }
+        Type::Struct(def) if is_u256(def) => {
+            let number = match value {
+                Value::Number(number) => number.to_string(),
+                Value::String(number) => number.to_owned(),
+                _ => return Err(type_err(path, tp, value)),
+            };
+            parse_u256(&number).map_err(|err| anyhow!("{}: {}", path, err))?
+        }
+        Type::Struct(def) => {
+            let fields = structs
+                .struct_fields(def)
+                .map_err(|err| anyhow!("{}: {}", path, err))?;
+            let values = match value {
+                Value::Object(values) => {
+                    let names = fields
+                        .iter()
+                        .map(|field| field.name.clone())
+                        .collect::<Vec<_>>();
+                    ensure_known_keys(values, &names, path, "field")?;
+                    fields
+                        .iter()
+                        .map(|field| {
+                            let path = format!("{}.{}", path, field.name);
+                            let val = values
+                                .get(&field.name)
+                                .ok_or_else(|| anyhow!("{}: field is missing", path))?;
+                            prepare_json_arg(&field.tp, val, &path, addr_map, structs)
+                        })
+                        .collect::<Result<_, Error>>()?
+                }
+                Value::Array(values) => {
+                    ensure!(
+                        values.len() == fields.len(),
+                        "{}: struct {} has {} fields, {} are passed",
+                        path,
+                        tp,
+                        fields.len(),
+                        values.len()
+                    );
+                    fields
+                        .iter()
+                        .zip(values)
+                        .enumerate()
+                        .map(|(idx, (field, val))| {
+                            let path = format!("{}[{}]", path, idx);
+                            prepare_json_arg(&field.tp, val, &path, addr_map, structs)
+                        })
+                        .collect::<Result<_, Error>>()?
+                }
+                _ => return Err(type_err(path, tp, value)),
+            };
+            ScriptArg::Struct(values)
+        }
+        Type::Signer
+        | Type::Reference(_)
+        | Type::MutableReference(_)
+        | Type::TypeParameter(_) => {
+            bail!("{}: unexpected script parameter {}", path, tp)
+        }
+    })
+}
+
+fn map_items<T, F>(items: &[Value], path: &str, f: F) -> Result<Vec<T>, Error>
+where
+    F: Fn(&Value, &str) -> Result<T, Error>,
+{
+    items
+        .iter()
+        .enumerate()
+        .map(|(idx, item)| f(item, &format!("{}[{}]", path, idx)))
+        .collect()
+}
+
+fn as_bool(value: &Value, path: &str) -> Result<bool, Error> {
+    value
+        .as_bool()
+        .ok_or_else(|| type_err(path, &Type::Bool, value))
+}
+
+fn as_number<T>(value: &Value, path: &str, tp: &Type) -> Result<T, Error>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let number = match value {
+        Value::Number(number) => number.to_string(),
+        // Large numbers may be passed as strings to avoid precision loss.
+        Value::String(number) => number.to_owned(),
+        _ => return Err(type_err(path, tp, value)),
+    };
+    number
+        .parse()
+        .map_err(|err| anyhow!("{}: failed to parse {} as {}. {}", path, number, tp, err))
+}
+
+fn as_address(
+    value: &Value,
+    path: &str,
+    addr_map: &AddressDeclarations,
+) -> Result<move_core_types::account_address::AccountAddress, Error> {
+    let address = value
+        .as_str()
+        .ok_or_else(|| type_err(path, &Type::Address, value))?;
+    parse_address(address, addr_map).map_err(|err| anyhow!("{}: {}", path, err))
+}
+
+fn ensure_known_keys(
+    values: &Map<String, Value>,
+    names: &[String],
+    path: &str,
+    kind: &str,
+) -> Result<(), Error> {
+    match values.keys().find(|key| !names.contains(key)) {
+        Some(key) => bail!("{}.{}: unknown {}", path, key, kind),
+        None => Ok(()),
+    }
+}
+
+fn type_err(path: &str, tp: &Type, value: &Value) -> Error {
+    anyhow!("{}: {} is expected. Actual:'{}'", path, tp, value)
+}
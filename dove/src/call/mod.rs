@@ -9,8 +9,12 @@ use crate::call::parser::Call;
 pub mod bytecode;
 /// Command helper.
 pub mod cmd;
+/// Transaction arguments decoder.
+pub mod decode;
 /// Function call.
 pub mod fn_call;
+/// JSON arguments.
+pub mod json;
 /// Transaction model.
 pub mod model;
 /// Call parser.
@@ -39,6 +43,7 @@ pub fn make_transaction(
             func,
             type_tag,
             args,
+            declaration.json_args.as_ref(),
             declaration.package,
             cfg,
         ),
@@ -52,6 +57,7 @@ pub fn make_transaction(
             name,
             type_tag,
             args,
+            declaration.json_args.as_ref(),
             declaration.package,
             cfg,
         ),
@@ -1,10 +1,9 @@
 use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::TypeTag;
 use anyhow::Error;
-use move_core_types::value::MoveValue;
+use move_core_types::value::{MoveStruct, MoveValue};
 use std::str::FromStr;
 use move_core_types::account_address::AccountAddress;
-use move_core_types::transaction_argument::TransactionArgument;
 use serde::{Deserialize, Serialize};
 use move_symbol_pool::Symbol;
 use lang::bytecode::info::BytecodeInfo;
@@ -85,7 +84,8 @@ impl Transaction {
         }))
     }
 
-    fn make_args(args: Vec<ScriptArg>) -> Result<Vec<Vec<u8>>, Error> {
+    /// Encodes script arguments with BCS.
+    pub fn make_args(args: Vec<ScriptArg>) -> Result<Vec<Vec<u8>>, Error> {
         args.into_iter()
             .map(ScriptArg::into)
             .map(|val: MoveValue| bcs::to_bytes(&val))
@@ -131,6 +131,10 @@ pub enum ScriptArg {
     VectorBool(Vec<bool>),
     /// vector<address>
     VectorAddress(Vec<AccountAddress>),
+    /// Vector of nested vectors or structs.
+    Vector(Vec<ScriptArg>),
+    /// Struct fields in declaration order.
+    Struct(Vec<ScriptArg>),
 }
 
 impl From<ScriptArg> for MoveValue {
@@ -154,29 +158,16 @@ impl From<ScriptArg> for MoveValue {
             ScriptArg::VectorAddress(val) => {
                 MoveValue::Vector(val.into_iter().map(MoveValue::Address).collect())
             }
+            ScriptArg::Vector(val) => {
+                MoveValue::Vector(val.into_iter().map(MoveValue::from).collect())
+            }
+            ScriptArg::Struct(fields) => MoveValue::Struct(MoveStruct::new(
+                fields.into_iter().map(MoveValue::from).collect(),
+            )),
         }
     }
 }
 
-impl TryInto<TransactionArgument> for ScriptArg {
-    type Error = Error;
-
-    fn try_into(self) -> Result<TransactionArgument, Self::Error> {
-        Ok(match self {
-            ScriptArg::U8(val) => TransactionArgument::U8(val),
-            ScriptArg::U64(val) => TransactionArgument::U64(val),
-            ScriptArg::U128(val) => TransactionArgument::U128(val),
-            ScriptArg::Bool(val) => TransactionArgument::Bool(val),
-            ScriptArg::Address(val) => TransactionArgument::Address(val),
-            ScriptArg::VectorU8(val) => TransactionArgument::U8Vector(val),
-            ScriptArg::VectorU64(_)
-            | ScriptArg::VectorU128(_)
-            | ScriptArg::VectorBool(_)
-            | ScriptArg::VectorAddress(_) => bail!("Unssuported transaction args."),
-        })
-    }
-}
-
 /// Signer type.
 #[derive(Serialize, Deserialize, Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Signer {
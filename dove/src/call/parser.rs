@@ -269,28 +269,23 @@ fn parse_args(ctx: &mut Context) -> Result<Vec<String>, Error> {
             }
 
             let mut token = String::new();
-            token.push_str(ctx.tokens.content());
-            let sw = ctx.tokens.peek() == Tok::LBracket;
-            ctx.tokens.advance().map_err(map_err)?;
-            if sw {
-                while ctx.tokens.peek() != Tok::RBracket {
-                    token.push_str(ctx.tokens.content());
-                    ctx.tokens
-                        .advance()
-                        .map_err(|_| anyhow!("{}", &error_message))?;
+            let mut depth = 0_usize;
+            loop {
+                match ctx.tokens.peek() {
+                    Tok::EOF => anyhow::bail!("{}", &error_message),
+                    Tok::Comma | Tok::RParen if depth == 0 => break,
+                    Tok::LBracket | Tok::LBrace => depth += 1,
+                    Tok::RBracket | Tok::RBrace => {
+                        depth = depth
+                            .checked_sub(1)
+                            .ok_or_else(|| anyhow!("{}", &error_message))?;
+                    }
+                    _ => {}
                 }
                 token.push_str(ctx.tokens.content());
-            } else {
-                while ctx.tokens.peek() != Tok::Comma && ctx.tokens.peek() != Tok::RParen {
-                    token.push_str(ctx.tokens.content());
-                    ctx.tokens.advance().map_err(map_err)?;
-                }
+                ctx.tokens.advance().map_err(map_err)?;
             }
             arguments.push(token);
-            if !sw && ctx.tokens.peek() == Tok::RParen {
-                break;
-            }
-            ctx.tokens.advance().map_err(map_err)?;
         }
         Ok(arguments)
     } else {
@@ -362,6 +357,54 @@ where
     Ok(elements)
 }
 
+/// Splits a vector `[a, b, ...]` or a struct `{a, b, ...}` literal into top-level items.
+/// Nested vectors and structs are kept as is.
+pub(crate) fn split_items(tkn: &str, open: char, close: char) -> Result<Vec<String>, Error> {
+    let tkn = tkn.trim();
+    let body = tkn
+        .strip_prefix(open)
+        .and_then(|body| body.strip_suffix(close))
+        .ok_or_else(|| {
+            anyhow!(
+                "Value in format {}v1, v2, ..., vn{} is expected. Actual:'{}'",
+                open,
+                close,
+                tkn
+            )
+        })?;
+
+    let mut items = vec![];
+    let mut item = String::new();
+    let mut depth = 0_usize;
+    for ch in body.chars() {
+        match ch {
+            '[' | '{' => depth += 1,
+            ']' | '}' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| anyhow!("Unbalanced brackets in '{}'", tkn))?;
+            }
+            ',' if depth == 0 => {
+                items.push(item.trim().to_owned());
+                item.clear();
+                continue;
+            }
+            _ => {}
+        }
+        item.push(ch);
+    }
+    ensure!(depth == 0, "Unbalanced brackets in '{}'", tkn);
+    if !item.trim().is_empty() {
+        items.push(item.trim().to_owned());
+    }
+    ensure!(
+        items.iter().all(|item| !item.is_empty()),
+        "Empty element in '{}'",
+        tkn
+    );
+    Ok(items)
+}
+
 #[cfg(test)]
 mod tests_call_parser {
     use std::collections::BTreeMap;
@@ -370,7 +413,7 @@ mod tests_call_parser {
     use move_core_types::language_storage::{StructTag, TypeTag};
     use move_core_types::language_storage::CORE_CODE_ADDRESS;
     use move_symbol_pool::Symbol;
-    use crate::call::parser::parse_call;
+    use crate::call::parser::{parse_call, split_items};
 
     #[test]
     fn func_call() {
@@ -515,6 +558,41 @@ mod tests_call_parser {
         assert_eq!(args, Vec::<String>::new());
     }
 
+    #[test]
+    fn nested_args() {
+        let (_, _, args) = parse_call(
+            &Default::default(),
+            "create_account([[1, 2], [3]], {10, [true], {0x1}}, [], 1)",
+        )
+        .unwrap()
+        .script();
+        assert_eq!(
+            args,
+            vec![
+                "[[1,2],[3]]".to_owned(),
+                "{10,[true],{0x1}}".to_owned(),
+                "[]".to_owned(),
+                "1".to_owned(),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_nested_items() {
+        assert_eq!(
+            split_items("[[1,2],[3], []]", '[', ']').unwrap(),
+            vec!["[1,2]".to_owned(), "[3]".to_owned(), "[]".to_owned()]
+        );
+        assert_eq!(
+            split_items("{10, {true, [0x1]},}", '{', '}').unwrap(),
+            vec!["10".to_owned(), "{true, [0x1]}".to_owned()]
+        );
+        assert!(split_items("[]", '[', ']').unwrap().is_empty());
+        assert!(split_items("[1, [2]", '[', ']').is_err());
+        assert!(split_items("[1,,2]", '[', ']').is_err());
+        assert!(split_items("{1}", '[', ']').is_err());
+    }
+
     #[test]
     fn named_address() {
         let mut map = BTreeMap::new();
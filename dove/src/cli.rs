@@ -12,12 +12,21 @@ use crate::{
     DOVE_VERSION, DOVE_HASH, MOVE_STDLIB_VERSION, DIEM_VERSION, DIEM_HASH, ERROR_DESCRIPTIONS,
 };
 use crate::cmd::clean::Clean;
+use crate::cmd::fuzz::Fuzz;
+use crate::cmd::gas_schedule::GasScheduleCommand;
+use crate::cmd::repl::Repl;
 use crate::cmd::run::Run;
+use crate::cmd::sandbox::SandboxCommand;
+use crate::cmd::scenario::ScenarioCommand;
+use crate::cmd::state::StateCommand;
 use crate::cmd::call::ExecuteTransaction;
 use crate::cmd::key::KeyCommand;
 use crate::cmd::deploy::Deploy;
 use crate::cmd::view::View;
+use crate::cmd::trace::TraceCommand;
+use crate::cmd::tx::TxCommand;
 use crate::context::Context;
+use crate::gas_schedule::load_cost_table;
 use crate::natives::{all_natives, pontem_cost_table};
 
 #[derive(Parser)]
@@ -30,6 +39,11 @@ struct DoveOpt {
     #[clap(flatten)]
     pub move_args: Move,
 
+    /// Cost table file in TOML or JSON format.
+    /// Overrides `gas_schedule` of the Move.toml package section.
+    #[clap(long = "gas-schedule", global = true, parse(from_os_str))]
+    pub gas_schedule: Option<PathBuf>,
+
     #[clap(subcommand)]
     pub cmd: DoveCommands,
 }
@@ -93,6 +107,58 @@ pub enum DoveCommands {
         #[clap(flatten)]
         cmd: View,
     },
+    #[clap(
+        about = "Inspect, sign and submit transaction files",
+        subcommand,
+        display_order = 20
+    )]
+    Tx(TxCommand),
+    #[clap(
+        about = "Inspect execution traces of `dove run --trace`",
+        subcommand,
+        display_order = 21
+    )]
+    Trace(TraceCommand),
+    #[clap(
+        about = "Run multi-step scenarios against the sandbox storage",
+        subcommand,
+        display_order = 22
+    )]
+    Scenario(ScenarioCommand),
+    #[clap(
+        about = "Interactive session against the sandbox storage",
+        display_order = 23
+    )]
+    Repl {
+        #[clap(flatten)]
+        cmd: Repl,
+    },
+    #[clap(
+        about = "Fuzz a function with arguments generated by its signature",
+        display_order = 24
+    )]
+    Fuzz {
+        #[clap(flatten)]
+        cmd: Fuzz,
+    },
+    #[clap(
+        about = "Seed the sandbox storage from a state file",
+        subcommand,
+        display_order = 25
+    )]
+    State(StateCommand),
+    #[clap(
+        about = "Manage named sandboxes and their snapshots",
+        subcommand,
+        display_order = 26
+    )]
+    Sandbox(SandboxCommand),
+    #[clap(
+        about = "Dump and check the cost table of the local execution",
+        subcommand,
+        display_order = 27
+    )]
+    GasSchedule(GasScheduleCommand),
 }
 
 fn preprocess_args(args: Vec<String>) -> Vec<String> {
@@ -121,21 +187,29 @@ pub fn execute(args: Vec<String>, cwd: PathBuf) -> Result<()> {
         check_dove_version(&minimal_version)?;
     }
     let args = preprocess_args(args);
-    let DoveOpt { move_args, cmd } = DoveOpt::parse_from(args);
+    let DoveOpt {
+        move_args,
+        gas_schedule,
+        cmd,
+    } = DoveOpt::parse_from(args);
 
-    // `dove clean`|`dove key` needs empty context and no preparation, so try it before other commands
+    // `dove clean`|`dove key`|`dove gas-schedule` needs empty context and no preparation, so try it before other commands
     match cmd {
         DoveCommands::Clean { mut cmd } => {
             cmd.apply(&cwd);
             return Ok(());
         }
         DoveCommands::Key(mut cmd) => return cmd.apply(),
+        DoveCommands::GasSchedule(mut cmd) => return cmd.apply(),
         _ => (),
     };
 
     let error_descriptions: ErrorMapping = bcs::from_bytes(ERROR_DESCRIPTIONS)?;
     let native_functions = all_natives();
-    let cost_table = pontem_cost_table();
+    let cost_table = match gas_schedule.or_else(|| get_gas_schedule_path(&cwd)) {
+        Some(path) => load_cost_table(&path)?,
+        None => pontem_cost_table(),
+    };
 
     // process all diem commands before dove commands
     if let DoveCommands::DiemCommand(cmd) = cmd {
@@ -161,6 +235,13 @@ pub fn execute(args: Vec<String>, cwd: PathBuf) -> Result<()> {
         DoveCommands::Call { mut cmd } => cmd.apply(&mut ctx),
         DoveCommands::Deploy { mut cmd } => cmd.apply(&mut ctx),
         DoveCommands::View { mut cmd } => cmd.apply(&mut ctx),
+        DoveCommands::Tx(mut cmd) => cmd.apply(&mut ctx),
+        DoveCommands::Trace(mut cmd) => cmd.apply(&mut ctx),
+        DoveCommands::Scenario(mut cmd) => cmd.apply(&mut ctx),
+        DoveCommands::Repl { mut cmd } => cmd.apply(&mut ctx),
+        DoveCommands::Fuzz { mut cmd } => cmd.apply(&mut ctx),
+        DoveCommands::State(mut cmd) => cmd.apply(&mut ctx),
+        DoveCommands::Sandbox(mut cmd) => cmd.apply(&mut ctx),
         DoveCommands::Build
         | DoveCommands::Test
         | DoveCommands::Prove
@@ -168,7 +249,10 @@ pub fn execute(args: Vec<String>, cwd: PathBuf) -> Result<()> {
         | DoveCommands::Init => {
             unreachable!("Should never be reached, as all those commands are preprocessed into package-prefixed commands")
         }
-        DoveCommands::Clean { .. } | DoveCommands::DiemCommand(_) | DoveCommands::Key { .. } => {
+        DoveCommands::Clean { .. }
+        | DoveCommands::DiemCommand(_)
+        | DoveCommands::Key { .. }
+        | DoveCommands::GasSchedule(_) => {
             unreachable!("Handled in the beginning")
         }
     }
@@ -226,6 +310,17 @@ fn get_minimal_dove_version(project_path: &Path) -> Option<String> {
         .and_then(|name| name.as_str().map(|t| t.to_string()))
 }
 
+/// Cost table file from the Move.toml package section, relative to the project directory.
+fn get_gas_schedule_path(project_path: &Path) -> Option<PathBuf> {
+    let move_toml_content = std::fs::read_to_string(project_path.join("Move.toml")).ok()?;
+    let move_toml = toml::from_str::<toml::Value>(&move_toml_content).ok()?;
+    move_toml
+        .get("package")
+        .and_then(|pack| pack.get("gas_schedule"))
+        .and_then(|path| path.as_str())
+        .map(|path| project_path.join(path))
+}
+
 #[cfg(test)]
 mod tests {
     use semver::Version;
@@ -6,16 +6,19 @@ use clap::Parser;
 
 use crate::context::Context;
 use crate::dot_move_folder;
+use crate::sandbox::SANDBOXES_DIR;
 
 #[derive(Parser, Debug, Default)]
 pub struct Clean {
     // Directories will be deleted
     // [state] Clear only the executor state:
     //      PROJECT_DIR/storage
+    //      PROJECT_DIR/sandboxes
     //      PROJECT_DIR/build/mv_interfaces
     //      PROJECT_DIR/build/package
     // [all] Clear all:
     //      PROJECT_DIR/storage
+    //      PROJECT_DIR/sandboxes
     //      PROJECT_DIR/build
     #[clap(help = "Type of cleaning. [default=all]\n\
                         state - Clear only the executor state.\n\
@@ -39,6 +42,7 @@ impl Clean {
             ClearType::State => {
                 vec![
                     project_root_dir.join("storage"),
+                    project_root_dir.join(SANDBOXES_DIR),
                     project_root_dir.join("build").join("mv_interfaces"),
                     project_root_dir.join("build").join("package"),
                 ]
@@ -47,6 +51,7 @@ impl Clean {
             ClearType::All => {
                 vec![
                     project_root_dir.join("storage"),
+                    project_root_dir.join(SANDBOXES_DIR),
                     project_root_dir.join("build"),
                 ]
             }
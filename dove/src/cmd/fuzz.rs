@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use std::fs;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Result;
+use clap::Parser;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use move_core_types::account_address::AccountAddress;
+use lang::bytecode::accessor::BytecodeType;
+use lang::bytecode::info::{Script, Type};
+use lang::bytecode::{find, SearchParams};
+
+use crate::call::bytecode::DoveBytecode;
+use crate::call::parser::{parse_call, Call};
+use crate::cmd::deploy::run_dove_package_build;
+use crate::context::Context;
+use crate::executor::Executor;
+use crate::fuzz::{Arg, ArgGenerator, Failure, Mode, Target};
+
+/// Fuzz the function with the arguments generated by its signature.
+#[derive(Parser, Debug)]
+#[clap(about = "dove fuzz [CALL] [OPTIONS]\n
+    Examples:
+    $ dove fuzz 'Store::store_u64'
+    $ dove fuzz 'Store::store_u64' --runs 1000 --seed 42
+    $ dove fuzz 'Store::store_u64' --allow-abort 1 2
+")]
+pub struct Fuzz {
+    /// Function or script without arguments, e.g. `Module::func` or `script_name<u8>`.
+    #[clap(name = "CALL")]
+    call: String,
+
+    /// Number of executions.
+    #[clap(long = "runs", short = 'n', default_value = "100")]
+    runs: usize,
+
+    /// Seed of the random generator. Random by default.
+    #[clap(long = "seed")]
+    seed: Option<u64>,
+
+    /// Abort codes expected from the function.
+    #[clap(long = "allow-abort", multiple_values = true)]
+    allow_abort: Vec<u64>,
+
+    /// Package name to search the function in.
+    #[clap(long = "package", short = 'p')]
+    package: Option<String>,
+
+    #[clap(long = "gas_budget", short = 'g', default_value = "1000000")]
+    gas_budget: u64,
+}
+
+impl Fuzz {
+    pub fn apply(&mut self, ctx: &mut Context) -> Result<()> {
+        run_dove_package_build(ctx)?;
+
+        let name = self
+            .call
+            .split('(')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        let parameters = self.parameters(ctx, &name)?;
+
+        let fuzz_dir = ctx.project_root_dir.join("build").join("fuzz");
+        let storage_dir = fuzz_dir.join("storage");
+        if storage_dir.exists() {
+            fs::remove_dir_all(&storage_dir)?;
+        }
+        let target = Target::new(
+            ctx,
+            Executor::new(ctx, &storage_dir)?,
+            name.clone(),
+            self.package.clone(),
+            self.gas_budget,
+            self.allow_abort.clone(),
+        );
+
+        let seed = self.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|time| time.as_secs())
+                .unwrap_or_default()
+        });
+        println!("Fuzzing {} with seed {}", name, seed);
+
+        let structs = DoveBytecode::new(ctx);
+        let mut addresses = ctx
+            .address_declarations()
+            .values()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        addresses.push(AccountAddress::ONE);
+        let mut generator = ArgGenerator::new(StdRng::seed_from_u64(seed), addresses, &structs);
+
+        let mut failures: Vec<(Failure, Vec<Arg>)> = vec![];
+        let mut counts: HashMap<Failure, usize> = HashMap::new();
+        for run in 0..self.runs {
+            let mode = match run {
+                0 => Mode::Min,
+                1 => Mode::Max,
+                _ => Mode::Random,
+            };
+            let args = parameters
+                .iter()
+                .map(|tp| generator.generate(tp, mode))
+                .collect::<Result<Vec<_>>>()?;
+            if let Some(failure) = target.run(&args)? {
+                let count = counts.entry(failure.clone()).or_default();
+                if *count == 0 {
+                    failures.push((failure, args));
+                }
+                *count += 1;
+            }
+        }
+
+        if failures.is_empty() {
+            fs::remove_dir_all(&storage_dir)?;
+            println!("{} runs, no failures found", self.runs);
+            return Ok(());
+        }
+
+        let calls_dir = fuzz_dir.join(
+            name.replace("::", "_")
+                .replace(&['<', '>', ',', ' '][..], ""),
+        );
+        fs::create_dir_all(&calls_dir)?;
+        for (idx, (failure, args)) in failures.iter().enumerate() {
+            let args = target.minimize(args.clone(), failure)?;
+            let call = target.call(&args);
+            let path = calls_dir.join(format!("failure_{}.call", idx));
+            fs::write(&path, format!("{}\n", call))?;
+
+            println!("Failure #{}: {} ({} runs)", idx, failure, counts[failure]);
+            println!("    input: {}", call);
+            println!("    saved: {}", path.display());
+            println!("    replay: dove run '{}'", call);
+        }
+        fs::remove_dir_all(&storage_dir)?;
+
+        bail!(
+            "{} runs, {} distinct failures found",
+            self.runs,
+            failures.len()
+        )
+    }
+
+    /// Returns the parameter types of the function with the call type arguments substituted.
+    fn parameters(&self, ctx: &Context, name: &str) -> Result<Vec<Type>> {
+        let (address, module, func, type_tag) =
+            match parse_call(&ctx.address_declarations(), name)? {
+                Call::Function {
+                    address,
+                    module,
+                    func,
+                    type_tag,
+                    ..
+                } => (address, Some(module), func, type_tag),
+                Call::Script { name, type_tag, .. } => (None, None, name, type_tag),
+            };
+
+        let functions = find(
+            DoveBytecode::new(ctx),
+            SearchParams {
+                tp: Some(if module.is_some() {
+                    BytecodeType::Module
+                } else {
+                    BytecodeType::Script
+                }),
+                package: self.package.as_deref(),
+                name: Some(module.as_ref().unwrap_or(&func).as_str()),
+            },
+        )?
+        .filter_map(|info| info.ok())
+        .filter(|info| address.is_none() || info.address() == address)
+        .filter(|info| {
+            module
+                .as_ref()
+                .map_or(true, |module| info.name() == module.as_str())
+        })
+        .filter_map(|info| info.find_public_function(func.as_str()))
+        .collect::<Vec<Script>>();
+        let function = match functions.as_slice() {
+            [function] => function,
+            [] => bail!("Couldn't find the function {}", name),
+            _ => bail!(
+                "More than one function {} was found. Please pass the package name.",
+                name
+            ),
+        };
+        ensure!(
+            function.type_params_count() == type_tag.len(),
+            "The function {} takes {} type parameters, {} are passed",
+            name,
+            function.type_params_count(),
+            type_tag.len()
+        );
+
+        let type_args = type_tag.iter().map(Type::from).collect::<Vec<_>>();
+        Ok(function
+            .parameters
+            .iter()
+            .map(|tp| tp.instantiate(&type_args))
+            .collect())
+    }
+}
@@ -0,0 +1,78 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+
+use crate::gas_schedule::{load_cost_table, GasSchedule};
+use crate::natives::pontem_cost_table;
+
+/// Cost table of the local execution.
+#[derive(Debug, Parser)]
+pub enum GasScheduleCommand {
+    /// Print the default cost table.
+    #[clap(
+        name = "dump",
+        about = "dove gas-schedule dump [OPTIONS]\n
+    Examples:
+    $ dove gas-schedule dump
+    $ dove gas-schedule dump --json
+    $ dove gas-schedule dump --output gas.toml
+"
+    )]
+    Dump {
+        /// Sets output format to JSON. Chosen automatically if the output file extension is `.json`.
+        #[clap(long = "json", short = 'j')]
+        json: bool,
+
+        /// Output file. Default: stdout.
+        #[clap(long = "output", short = 'o', parse(from_os_str))]
+        output: Option<PathBuf>,
+    },
+    /// Check that the cost table file has an entry for each instruction and native function.
+    #[clap(
+        name = "check",
+        about = "dove gas-schedule check [PATH] [OPTIONS]\n
+    Examples:
+    $ dove gas-schedule check gas.toml
+"
+    )]
+    Check {
+        /// Cost table in TOML or JSON format.
+        #[clap(name = "PATH", parse(from_os_str))]
+        path: PathBuf,
+    },
+}
+
+impl GasScheduleCommand {
+    pub fn apply(&mut self) -> Result<()> {
+        match self {
+            GasScheduleCommand::Dump { json, output } => {
+                let json = *json
+                    || output
+                        .as_ref()
+                        .and_then(|path| path.extension())
+                        .map_or(false, |ext| ext == "json");
+                let schedule = GasSchedule::from_cost_table(&pontem_cost_table());
+                let content = if json {
+                    serde_json::to_string_pretty(&schedule)?
+                } else {
+                    toml::to_string(&schedule)?
+                };
+                match output {
+                    Some(path) => {
+                        fs::write(path.as_path(), content)?;
+                        println!("Gas schedule is written to {}", path.display());
+                    }
+                    None => println!("{}", content),
+                }
+                Ok(())
+            }
+            GasScheduleCommand::Check { path } => {
+                load_cost_table(path)?;
+                println!("Gas schedule {} is valid", path.display());
+                Ok(())
+            }
+        }
+    }
+}
@@ -4,9 +4,25 @@ pub mod call;
 pub mod clean;
 /// Project builder.
 pub mod deploy;
+/// Function fuzzer.
+pub mod fuzz;
+/// Cost table of the local execution.
+pub mod gas_schedule;
 /// Manage wallet keys
 pub mod key;
+/// Interactive session.
+pub mod repl;
 /// Script executor.
 pub mod run;
+/// Named sandboxes and snapshots.
+pub mod sandbox;
+/// Multi-step scenarios.
+pub mod scenario;
+/// Sandbox storage state.
+pub mod state;
+/// Execution traces.
+pub mod trace;
+/// Transaction files.
+pub mod tx;
 /// resource-viewer
 pub mod view;
@@ -0,0 +1,203 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use anyhow::Result;
+use clap::Parser;
+use move_core_types::account_address::AccountAddress;
+
+use crate::call::bytecode::load_source_map;
+use crate::call::cmd::CallDeclarationCmd;
+use crate::call::fn_call::Config;
+use crate::call::make_transaction;
+use crate::call::model::EnrichedTransaction;
+use crate::cmd::deploy::run_dove_package_build;
+use crate::cmd::run::return_types;
+use crate::cmd::view::{parse_account, View};
+use crate::context::Context;
+use crate::diagnostics::emit_vm_error;
+use crate::dot_move_folder;
+use crate::executor::Executor;
+use crate::report::annotate_values;
+use crate::sandbox::storage_dir;
+
+/// REPL history file in the `.move` directory.
+const HISTORY_FILE: &str = "repl_history";
+
+const HELP: &str = "Commands:
+    <call>                  Execute a call, e.g. `Store::store_u64(Demo, 100)`, and commit its effects.
+                            Signers may be omitted if the session signer is set.
+    :signer [ACCOUNT|-]     Show, set or reset the session signer.
+    :view QUERY [OPTIONS]   View resources of the sandbox storage, e.g. `:view Demo` or `:view Demo::Store::U64`.
+    :build                  Rebuild the project.
+    :history [N]            Print the last N commands. Default: 20.
+    :help                   Print this help.
+    :quit                   Exit the session.";
+
+/// Interactive session against the project sandbox storage.
+#[derive(Parser, Debug)]
+#[clap(about = "dove repl [OPTIONS]\n
+    Examples:
+    $ dove repl
+    $ dove repl --signer Demo
+")]
+pub struct Repl {
+    /// Session signer: an address, a Move.toml alias or an ss58 address.
+    #[clap(long = "signer", short = 's')]
+    signer: Option<String>,
+
+    #[clap(long = "gas_budget", short = 'g', default_value = "1000000000")]
+    gas_budget: u64,
+
+    /// Sandbox name. Default: the project storage.
+    #[clap(long = "sandbox")]
+    sandbox: Option<String>,
+}
+
+impl Repl {
+    pub fn apply(&mut self, ctx: &mut Context) -> Result<()> {
+        let mut signer = self
+            .signer
+            .as_deref()
+            .map(|signer| parse_account(&ctx.address_declarations(), signer))
+            .transpose()?;
+
+        run_dove_package_build(ctx)?;
+        let history = dot_move_folder()?.join(HISTORY_FILE);
+        println!("Dove REPL. Type :help for the list of commands.");
+
+        let stdin = io::stdin();
+        let mut lines = stdin.lock().lines();
+        loop {
+            print!("dove> ");
+            io::stdout().flush()?;
+            let line = match lines.next() {
+                Some(line) => line?,
+                None => break,
+            };
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            save_history(&history, line)?;
+
+            let (command, args) = match line.split_once(char::is_whitespace) {
+                Some((command, args)) => (command, args.trim()),
+                None => (line, ""),
+            };
+            let res = match command {
+                ":quit" | ":exit" | ":q" => break,
+                ":help" | ":h" => {
+                    println!("{}", HELP);
+                    Ok(())
+                }
+                ":signer" => set_signer(ctx, &mut signer, args),
+                ":view" => view(ctx, args, self.sandbox.as_deref()),
+                ":build" => run_dove_package_build(ctx),
+                ":history" => print_history(&history, args),
+                _ if command.starts_with(':') => Err(anyhow!(
+                    "Unknown command {}. Type :help for the list of commands.",
+                    command
+                )),
+                _ => self.call(ctx, line, signer),
+            };
+            if let Err(err) = res {
+                println!("Error: {:?}", err);
+            }
+        }
+        Ok(())
+    }
+
+    fn call(&self, ctx: &Context, call: &str, signer: Option<AccountAddress>) -> Result<()> {
+        let tx = make_transaction(
+            ctx,
+            CallDeclarationCmd::new(call.to_string(), None),
+            Config::for_run().with_default_signer(signer),
+        )?;
+        let (returns, source_map) = match &tx {
+            EnrichedTransaction::Local {
+                bi,
+                func_name,
+                type_tag: type_args,
+                ..
+            } => (
+                return_types(bi, func_name.as_deref(), type_args)?,
+                load_source_map(bi).ok(),
+            ),
+            EnrichedTransaction::Global { .. } => unreachable!(),
+        };
+
+        let executor = Executor::new(ctx, &storage_dir(ctx, self.sandbox.as_deref())?)?;
+        let result = executor.execute(tx, self.gas_budget)?;
+        match result.effects {
+            Ok(effects) => {
+                for (idx, value) in annotate_values(&executor, &returns, &result.return_values)?
+                    .iter()
+                    .enumerate()
+                {
+                    println!("#{}: {} = {}", idx, value.tp, value.value);
+                }
+                executor.commit(effects)?;
+                println!("Executed. Gas used: {}", result.gas_used);
+            }
+            Err(err) => {
+                if !emit_vm_error(ctx, &err, source_map.as_ref())? {
+                    println!("{}", executor.explain_error(&err));
+                }
+                println!("Gas used: {}", result.gas_used);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn set_signer(ctx: &Context, signer: &mut Option<AccountAddress>, args: &str) -> Result<()> {
+    match args {
+        "" => {}
+        "-" => *signer = None,
+        account => *signer = Some(parse_account(&ctx.address_declarations(), account)?),
+    }
+    match signer {
+        Some(signer) => println!("Signer: {}", signer.to_hex_literal()),
+        None => println!("Signer is not set"),
+    }
+    Ok(())
+}
+
+/// Renders resources of the sandbox storage like `dove view --local`.
+fn view(ctx: &mut Context, args: &str, sandbox: Option<&str>) -> Result<()> {
+    let mut args = std::iter::once("view")
+        .chain(args.split_whitespace())
+        .collect::<Vec<_>>();
+    if !args.contains(&"--local") {
+        args.push("--local");
+    }
+    if let Some(sandbox) = sandbox {
+        if !args.contains(&"--sandbox") {
+            args.extend(["--sandbox", sandbox]);
+        }
+    }
+    View::try_parse_from(args)
+        .map_err(|err| anyhow!("{}", err))?
+        .apply(ctx)
+}
+
+fn save_history(path: &Path, line: &str) -> Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)?;
+    Ok(())
+}
+
+fn print_history(path: &Path, args: &str) -> Result<()> {
+    let count = if args.is_empty() {
+        20
+    } else {
+        args.parse::<usize>()?
+    };
+    let history = fs::read_to_string(path).unwrap_or_default();
+    let lines = history.lines().collect::<Vec<_>>();
+    for line in &lines[lines.len().saturating_sub(count)..] {
+        println!("    {}", line);
+    }
+    Ok(())
+}
@@ -3,22 +3,37 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use clap::Parser;
 use anyhow::{Error, Result};
+use reqwest::Url;
+use move_binary_format::CompiledScript;
 use move_bytecode_source_map::source_map::SourceMap;
-use lang::bytecode::info::BytecodeInfo;
-use move_cli::{DEFAULT_STORAGE_DIR, Move, run_cli};
-use move_cli::sandbox::cli::SandboxCommand;
-use move_cli::Command;
+use lang::bytecode::info::{BytecodeInfo, Type};
 use move_command_line_common::files::FileHash;
-
-use move_package::BuildConfig;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::resolver::{ModuleResolver, ResourceResolver};
 use move_package::compilation::package_layout::CompiledPackageLayout;
+use net::fixture::RecordingNet;
+use net::make_net;
+
 use crate::cmd::deploy::run_dove_package_build;
 use crate::context::Context;
+use crate::diagnostics::emit_vm_error;
 
+use crate::call::bytecode::load_source_map;
 use crate::call::cmd::CallDeclarationCmd;
+use crate::call::decode::type_tag;
 use crate::call::fn_call::Config;
 use crate::call::make_transaction;
 use crate::call::model::EnrichedTransaction;
+use crate::executor::{ExecutionResult, Executor};
+use crate::fork::ForkState;
+use crate::gas_profile::GasProfile;
+use crate::report::{annotate_values, ExecutionReport, ReportFormat};
+use crate::sandbox::storage_dir;
+use crate::state::{seed_state, StateFile};
+use crate::vm_trace::{self, CallResolver, VmTrace};
+
+/// VM instruction trace file in the build directory.
+const VM_TRACE_FILE: &str = "vm_trace";
 
 #[derive(Parser, Debug)]
 #[clap(about = "dove run [call] [OPTIONS]\n
@@ -29,6 +44,16 @@ use crate::call::model::EnrichedTransaction;
     $ dove run 'Module::function()'
     $ dove run '0x1::Module::function()'
     $ dove run '0x1::Module::function' --args [10,10] true ALIAS_ADDRESSES SS58_ADDRESS 100 0x1 --type '0x01::Dfinance::USD'
+    $ dove run 'script_name([[1,2],[3]], {10, [true]}, 340282366920938463463374607431768211456)'
+    $ dove run 'Module::view_function(0x1)' --json
+    $ dove run 'Module::function(10)' --report json
+    $ dove run 'script_name()' --gas-profile --folded gas.folded
+    $ dove run 'script_name()' --trace trace.txt
+    $ dove run 'Module::function()' --state fixtures.json
+    $ dove run 'Module::function()' --sandbox staging-sim
+    $ dove run 'Module::function()' --fork http://127.0.0.1:9933 --height 1000
+    $ dove run 'Module::function()' --fork http://127.0.0.1:9933 --record fork.json
+    $ dove run 'Module::function()' --fork file://fork.json
 ")]
 pub struct Run {
     #[clap(flatten)]
@@ -41,88 +66,294 @@ pub struct Run {
 
     #[clap(long = "gas_budget", short = 'g', default_value = "1000000000")]
     gas_budget: u64,
+
+    /// Print return values of the function in JSON format.
+    #[clap(long = "json", short = 'j')]
+    json: bool,
+
+    /// Print the execution report with the write set and events instead of the usual output.
+    /// Supported formats: json.
+    #[clap(long = "report")]
+    report: Option<ReportFormat>,
+
+    /// Print gas used per function, per instruction kind and per native function.
+    #[clap(long = "gas-profile", conflicts_with = "report")]
+    gas_profile: bool,
+
+    /// Write the gas profile in the folded stacks format for flamegraph tools.
+    #[clap(long = "folded", requires = "gas_profile", parse(from_os_str))]
+    folded: Option<PathBuf>,
+
+    /// Write the executed instructions to the trace file. Use `dove trace view` to inspect it.
+    #[clap(long = "trace", parse(from_os_str))]
+    trace: Option<PathBuf>,
+
+    /// Write the resources of the state file to the sandbox storage before the call.
+    /// See `dove state seed`.
+    #[clap(long = "state", parse(from_os_str))]
+    state: Option<PathBuf>,
+
+    /// Sandbox name. Default: the project storage.
+    #[clap(long = "sandbox")]
+    sandbox: Option<String>,
+
+    /// Execute against the chain state of the node without submitting anything.
+    /// Modules of the project shadow the chain modules, writes are kept in memory.
+    #[clap(long = "fork", conflicts_with_all = &["dry_run", "state", "sandbox"])]
+    fork: Option<Url>,
+
+    /// Block number of the forked state. Default: the latest block.
+    #[clap(long = "height", requires = "fork")]
+    height: Option<String>,
+
+    /// Record the node responses of the forked execution to a fixture file.
+    /// Replay it with `--fork file://<PATH>`.
+    #[clap(long = "record", requires = "fork", parse(from_os_str))]
+    record: Option<PathBuf>,
 }
 
 impl Run {
     pub fn apply(&mut self, ctx: &mut Context) -> Result<()> {
         run_dove_package_build(ctx)?;
         let tx = make_transaction(ctx, self.call.take(), Config::for_run())?;
-        match tx {
+        let info = match &tx {
             EnrichedTransaction::Local {
                 bi,
-                args,
-                type_tag,
                 func_name,
-                signers,
+                type_tag: type_args,
+                ..
             } => {
-                let script_file = resolve_script_name(&bi)?;
-
-                let args = args
-                    .into_iter()
-                    .map(|arg| arg.try_into())
-                    .collect::<Result<_, Error>>()?;
-                let cmd = SandboxCommand::Run {
+                let (script_file, source_map) = resolve_script_name(bi)?;
+                CallInfo {
                     script_file,
-                    script_name: func_name,
-                    signers: signers.iter().map(|addr| addr.to_hex_literal()).collect(),
-                    args,
-                    type_args: type_tag,
-                    gas_budget: self.gas_budget,
-                    dry_run: self.dry_run,
-                };
-
-                let named_addresses = ctx
-                    .address_declarations()
-                    .into_iter()
-                    .filter_map(|(k, v)| v.map(|v| (k, v)))
-                    .map(|(k, v)| (k.to_string(), v))
-                    .collect();
-
-                let move_args = Move {
-                    package_path: ctx.project_root_dir.clone(),
-                    verbose: ctx.move_args.verbose,
-                    build_config: BuildConfig {
-                        dev_mode: true,
-                        test_mode: true,
-                        generate_docs: false,
-                        generate_abis: false,
-                        install_dir: None,
-                        force_recompilation: false,
-                        additional_named_addresses: named_addresses,
-                        language_flavor: None,
-                    },
-                };
-                run_cli(
-                    ctx.native_functions.clone(),
-                    &ctx.cost_table,
-                    &ctx.error_descriptions,
-                    &move_args,
-                    &Command::Sandbox {
-                        storage_dir: PathBuf::from(DEFAULT_STORAGE_DIR),
-                        cmd,
-                    },
-                )
+                    source_map,
+                    returns: return_types(bi, func_name.as_deref(), type_args)?,
+                    script: compiled_script(bi, func_name.as_deref())?,
+                    script_path: func_name
+                        .is_none()
+                        .then(|| PathBuf::from(&bi.bytecode_ref().0)),
+                }
             }
             EnrichedTransaction::Global { .. } => unreachable!(),
+        };
+
+        let trace = if self.gas_profile || self.trace.is_some() {
+            ensure!(
+                cfg!(feature = "vm-trace"),
+                "--gas-profile and --trace require dove built with the `vm-trace` feature"
+            );
+            Some(vm_trace::enable(
+                &ctx.project_root_dir.join("build").join(VM_TRACE_FILE),
+            )?)
+        } else {
+            None
+        };
+
+        if let Some(url) = &self.fork {
+            let mut net = make_net(
+                url.clone(),
+                None,
+                &ctx.path_for_build(None, CompiledPackageLayout::Root),
+            )?;
+            ensure!(
+                self.height.is_none() || net.honors_height(),
+                "The node backend {} keeps only the latest state. Remove --height",
+                url
+            );
+            if let Some(path) = &self.record {
+                net = Box::new(RecordingNet::new(net, path)?);
+            }
+            // Every read goes to the same block, even if the chain moves on during the call.
+            let height = match &self.height {
+                Some(height) => Some(height.clone()),
+                None => net.latest_block()?,
+            };
+            let executor = Executor::with_state(ctx, ForkState::new(ctx, net, height.clone())?);
+            let result = executor.execute(tx, self.gas_budget)?;
+            self.print_result(ctx, &executor, &result, &info, trace.as_ref())?;
+            if self.report.is_none() {
+                print_effects(&executor, &result, &info.returns)?;
+                println!(
+                    "Forked from {} at block {}. Nothing is submitted.",
+                    url,
+                    height.as_deref().unwrap_or("latest")
+                );
+            }
+            return Ok(());
+        }
+
+        let executor = Executor::new(ctx, &storage_dir(ctx, self.sandbox.as_deref())?)?;
+        if let Some(path) = &self.state {
+            seed_state(ctx, executor.state(), &StateFile::load(path)?)?;
+        }
+        let result = executor.execute(tx, self.gas_budget)?;
+        self.print_result(ctx, &executor, &result, &info, trace.as_ref())?;
+        if ctx.move_args.verbose && self.report.is_none() {
+            print_effects(&executor, &result, &info.returns)?;
+        }
+
+        match result.effects {
+            Ok(effects) if !self.dry_run => executor.commit(effects),
+            Ok(_) if self.report.is_none() => {
+                println!(
+                    "Discarding changes; re-run without `--dry-run` if you would like to keep them."
+                );
+                Ok(())
+            }
+            _ => Ok(()),
         }
     }
 }
 
-fn resolve_script_name(bi: &BytecodeInfo) -> Result<PathBuf> {
+/// Details of the executed call used to render the result.
+struct CallInfo {
+    script_file: PathBuf,
+    source_map: SourceMap,
+    returns: Vec<TypeTag>,
+    script: Option<CompiledScript>,
+    script_path: Option<PathBuf>,
+}
+
+impl Run {
+    /// Prints the trace, the gas profile and the report or the return values and the error.
+    fn print_result<S>(
+        &self,
+        ctx: &Context,
+        executor: &Executor<S>,
+        result: &ExecutionResult,
+        info: &CallInfo,
+        trace: Option<&VmTrace>,
+    ) -> Result<()>
+    where
+        S: ModuleResolver<Error = Error> + ResourceResolver<Error = Error>,
+    {
+        if let Some(trace) = trace {
+            if let Some(path) = &self.trace {
+                trace.save(path, info.script_path.as_deref())?;
+            }
+            if self.gas_profile {
+                let entries = trace.entries()?;
+                let mut resolver = CallResolver::new(executor.state(), info.script.clone());
+                let profile =
+                    GasProfile::new(&entries, &mut resolver, &ctx.cost_table, result.gas_used);
+                println!("{}", profile);
+                if let Some(path) = &self.folded {
+                    profile.write_folded(path)?;
+                    println!("Estimated folded stacks are written to {}", path.display());
+                }
+            }
+        }
+
+        if let Some(ReportFormat::Json) = self.report {
+            let report = ExecutionReport::new(executor, result, &info.returns)?;
+            println!("{}", serde_json::to_string_pretty(&report)?);
+            return Ok(());
+        }
+
+        match &result.effects {
+            Ok(_) => self.print_return_values(executor, &info.returns, &result.return_values),
+            Err(err) => {
+                if !emit_vm_error(ctx, err, Some(&info.source_map))? {
+                    println!(
+                        "{}\n{}",
+                        info.script_file.display(),
+                        executor.explain_error(err)
+                    );
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn print_return_values<S>(
+        &self,
+        executor: &Executor<S>,
+        returns: &[TypeTag],
+        values: &[Vec<u8>],
+    ) -> Result<()>
+    where
+        S: ModuleResolver<Error = Error> + ResourceResolver<Error = Error>,
+    {
+        if values.is_empty() {
+            return Ok(());
+        }
+        let values = annotate_values(executor, returns, values)?;
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&values)?);
+        } else {
+            println!("Return values:");
+            for (idx, value) in values.iter().enumerate() {
+                println!("    #{}: {} = {}", idx, value.tp, value.value);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Prints the write set, the events and the gas used by the execution.
+fn print_effects<S>(
+    executor: &Executor<S>,
+    result: &ExecutionResult,
+    returns: &[TypeTag],
+) -> Result<()>
+where
+    S: ModuleResolver<Error = Error> + ResourceResolver<Error = Error>,
+{
+    let report = ExecutionReport::new(executor, result, returns)?;
+    if result.effects.is_ok() {
+        println!("Write set:");
+        for resource in &report.resources {
+            println!(
+                "    {} {} {}",
+                resource.change, resource.address, resource.tp
+            );
+            if let Some(after) = &resource.after {
+                println!(
+                    "        {}",
+                    after.0.to_string().replace('\n', "\n        ")
+                );
+            }
+        }
+        for module in &report.modules {
+            println!("    {} {}::{}", module.change, module.address, module.name);
+        }
+        println!("Events: {}", report.events.len());
+    }
+    println!("Gas used: {}", result.gas_used);
+    Ok(())
+}
+
+/// Returns the instantiated return types of the function.
+pub(crate) fn return_types(
+    bi: &BytecodeInfo,
+    func_name: Option<&str>,
+    type_args: &[TypeTag],
+) -> Result<Vec<TypeTag>> {
+    let func = match func_name.and_then(|name| bi.find_public_function(name)) {
+        Some(func) => func,
+        None => return Ok(vec![]),
+    };
+    let type_args = type_args.iter().map(Type::from).collect::<Vec<_>>();
+    func.returns
+        .iter()
+        .map(|tp| type_tag(&tp.instantiate(&type_args)))
+        .collect()
+}
+
+/// Returns the compiled script if the transaction is a script.
+fn compiled_script(bi: &BytecodeInfo, func_name: Option<&str>) -> Result<Option<CompiledScript>> {
+    if func_name.is_some() {
+        return Ok(None);
+    }
+    let mut script = Vec::new();
+    bi.serialize(&mut script)?;
+    Ok(Some(CompiledScript::deserialize(&script)?))
+}
+
+/// Returns the source file and the source map of the bytecode.
+fn resolve_script_name(bi: &BytecodeInfo) -> Result<(PathBuf, SourceMap)> {
     let path = PathBuf::from(&bi.bytecode_ref().0);
-    let name = path
-        .file_name()
-        .map(|name| name.to_string_lossy().to_string())
-        .ok_or_else(|| anyhow!("Failed to get file name:{:?}", path))?;
-    let package = path
-        .parent()
-        .and_then(|p| p.parent())
-        .ok_or_else(|| anyhow!("Failed to get package dir:{:?}", path))?;
-    let mut source_map = package
-        .join(CompiledPackageLayout::SourceMaps.path())
-        .join(name);
-    source_map.set_extension("mvsm");
-    let source_map: SourceMap = bcs::from_bytes(&fs::read(source_map)?)?;
+    let source_map = load_source_map(bi)?;
 
     let project_path = path
         .parent()
@@ -131,7 +362,7 @@ fn resolve_script_name(bi: &BytecodeInfo) -> Result<PathBuf> {
         .and_then(|parent| parent.parent())
         .ok_or_else(|| anyhow!("Failed to get project dir: {:?}", path))?;
 
-    find_loc(project_path, &source_map)
+    Ok((find_loc(project_path, &source_map)?, source_map))
 }
 
 fn find_loc(project_path: &Path, source_map: &SourceMap) -> Result<PathBuf> {
@@ -146,7 +377,7 @@ fn find_loc(project_path: &Path, source_map: &SourceMap) -> Result<PathBuf> {
 /// Search "move" files in the project
 /// Search is carried out in the directories: scripts, sources
 ///
-fn find_move_files_in_project(project_path: &Path) -> HashMap<FileHash, PathBuf> {
+pub(crate) fn find_move_files_in_project(project_path: &Path) -> HashMap<FileHash, PathBuf> {
     ["scripts", "sources"]
         .iter()
         .filter_map(|dir| find_move_files_in_dir(&project_path.join(dir)).ok())
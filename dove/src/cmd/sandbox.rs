@@ -0,0 +1,199 @@
+use anyhow::Result;
+use clap::Parser;
+use move_cli::sandbox::utils::on_disk_state_view::OnDiskStateView;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::parser::parse_struct_tag;
+use move_package::compilation::package_layout::CompiledPackageLayout;
+use move_resource_viewer::{AnnotatedMoveStruct, MoveValueAnnotator};
+use resource_viewer::diff::ResourceDiff;
+
+use crate::context::Context;
+use crate::sandbox::{
+    copy_storage, diff, list_dirs, snapshot_dir, storage_dir, Change, StorageItem, SANDBOXES_DIR,
+    SNAPSHOTS_DIR,
+};
+
+/// Named sandboxes and snapshots.
+#[derive(Debug, Parser)]
+pub enum SandboxCommand {
+    /// List the sandboxes and the snapshots.
+    #[clap(name = "list")]
+    List,
+    /// Save a copy of the sandbox storage.
+    #[clap(
+        name = "snapshot",
+        about = "dove sandbox snapshot [SNAPSHOT] [OPTIONS]\n
+    Examples:
+    $ dove sandbox snapshot prepared
+    $ dove sandbox snapshot prepared --sandbox staging-sim --force
+"
+    )]
+    Snapshot {
+        /// Snapshot name.
+        #[clap(name = "SNAPSHOT")]
+        snapshot: String,
+
+        /// Sandbox name. Default: the project storage.
+        #[clap(long = "sandbox")]
+        sandbox: Option<String>,
+
+        /// Overwrite the existing snapshot.
+        #[clap(long = "force", short = 'f')]
+        force: bool,
+    },
+    /// Replace the sandbox storage with the snapshot.
+    #[clap(
+        name = "restore",
+        about = "dove sandbox restore [SNAPSHOT] [OPTIONS]\n
+    Examples:
+    $ dove sandbox restore prepared
+    $ dove sandbox restore prepared --sandbox experiment
+"
+    )]
+    Restore {
+        /// Snapshot name.
+        #[clap(name = "SNAPSHOT")]
+        snapshot: String,
+
+        /// Sandbox name. Default: the project storage.
+        #[clap(long = "sandbox")]
+        sandbox: Option<String>,
+    },
+    /// Print the changes of the sandbox storage since the snapshot.
+    #[clap(
+        name = "diff",
+        about = "dove sandbox diff [SNAPSHOT] [OPTIONS]\n
+    Examples:
+    $ dove sandbox diff prepared
+    $ dove sandbox diff prepared --sandbox experiment
+    $ dove sandbox diff prepared --to other_snapshot
+"
+    )]
+    Diff {
+        /// Snapshot name.
+        #[clap(name = "SNAPSHOT")]
+        snapshot: String,
+
+        /// Sandbox name. Default: the project storage.
+        #[clap(long = "sandbox", conflicts_with = "to")]
+        sandbox: Option<String>,
+
+        /// Compare with another snapshot instead of the sandbox.
+        #[clap(long = "to")]
+        to: Option<String>,
+    },
+}
+
+impl SandboxCommand {
+    pub fn apply(&mut self, ctx: &mut Context) -> Result<()> {
+        match self {
+            SandboxCommand::List => {
+                println!("Sandboxes:");
+                println!("    default");
+                for name in list_dirs(&ctx.project_root_dir.join(SANDBOXES_DIR))? {
+                    println!("    {}", name);
+                }
+                println!("Snapshots:");
+                for name in list_dirs(&ctx.project_root_dir.join(SNAPSHOTS_DIR))? {
+                    println!("    {}", name);
+                }
+                Ok(())
+            }
+            SandboxCommand::Snapshot {
+                snapshot,
+                sandbox,
+                force,
+            } => {
+                let from = storage_dir(ctx, sandbox.as_deref())?;
+                let to = snapshot_dir(ctx, snapshot)?;
+                ensure!(
+                    from.exists(),
+                    "Sandbox {} is empty",
+                    sandbox.as_deref().unwrap_or("default")
+                );
+                ensure!(
+                    *force || !to.exists(),
+                    "Snapshot {} already exists. Use --force to overwrite it.",
+                    snapshot
+                );
+                copy_storage(&from, &to)?;
+                println!("Snapshot {} is saved", snapshot);
+                Ok(())
+            }
+            SandboxCommand::Restore { snapshot, sandbox } => {
+                let from = snapshot_dir(ctx, snapshot)?;
+                ensure!(from.exists(), "Snapshot {} not found", snapshot);
+                copy_storage(&from, &storage_dir(ctx, sandbox.as_deref())?)?;
+                println!(
+                    "Sandbox {} is restored from snapshot {}",
+                    sandbox.as_deref().unwrap_or("default"),
+                    snapshot
+                );
+                Ok(())
+            }
+            SandboxCommand::Diff {
+                snapshot,
+                sandbox,
+                to,
+            } => {
+                let from = snapshot_dir(ctx, snapshot)?;
+                ensure!(from.exists(), "Snapshot {} not found", snapshot);
+                let to_name = to.clone();
+                let to = match to {
+                    Some(to) => {
+                        let dir = snapshot_dir(ctx, to)?;
+                        ensure!(dir.exists(), "Snapshot {} not found", to);
+                        dir
+                    }
+                    None => storage_dir(ctx, sandbox.as_deref())?,
+                };
+
+                let changes = diff(&from, &to)?;
+                if changes.is_empty() {
+                    println!("No changes");
+                    return Ok(());
+                }
+                let build_dir = ctx.path_for_build(None, CompiledPackageLayout::Root);
+                let old_state = OnDiskStateView::create(&build_dir, &from)?;
+                let new_state = OnDiskStateView::create(&build_dir, &to)?;
+                let to_name = to_name
+                    .unwrap_or_else(|| sandbox.clone().unwrap_or_else(|| "default".to_string()));
+                for change in changes {
+                    println!("{}", change);
+                    let (item, old, new) = match &change {
+                        Change::Added(item) => (item, None, Some(&new_state)),
+                        Change::Removed(item) => (item, Some(&old_state), None),
+                        Change::Changed(item) => (item, Some(&old_state), Some(&new_state)),
+                    };
+                    if let StorageItem::Resource { address, tag } = item {
+                        let old = old.and_then(|state| view(state, *address, tag));
+                        let new = new.and_then(|state| view(state, *address, tag));
+                        if old.is_none() && new.is_none() {
+                            continue;
+                        }
+                        let diff = ResourceDiff::new(
+                            tag.clone(),
+                            (snapshot.clone(), old.as_ref()),
+                            (to_name.clone(), new.as_ref()),
+                        );
+                        println!("    {}", diff.to_string().replace('\n', "\n    "));
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Decodes the resource. Returns `None` if the resource can't be decoded.
+fn view(
+    state: &OnDiskStateView,
+    address: AccountAddress,
+    tag: &str,
+) -> Option<AnnotatedMoveStruct> {
+    let tag = parse_struct_tag(tag).ok()?;
+    let blob = state.get_resource_bytes(address, tag.clone()).ok()??;
+    MoveValueAnnotator::new(state)
+        .view_resource(&tag, &blob)
+        .ok()
+}
@@ -0,0 +1,89 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+
+use crate::cmd::deploy::run_dove_package_build;
+use crate::context::Context;
+use crate::scenario::{run_scenario, Scenario};
+
+/// Multi-step scenarios.
+#[derive(Debug, Parser)]
+pub enum ScenarioCommand {
+    /// Run the scenario steps against a fresh sandbox storage.
+    #[clap(
+        name = "run",
+        about = "dove scenario run [PATH_TO_FILE] [OPTIONS]\n
+    Examples:
+    $ dove scenario run scenarios/store.toml
+    $ dove scenario run scenarios/store.toml --junit report.xml --json report.json
+"
+    )]
+    Run {
+        /// Scenario file in TOML format.
+        #[clap(name = "PATH_TO_FILE", parse(from_os_str))]
+        path: PathBuf,
+
+        /// Write the step results in JUnit XML format.
+        #[clap(long = "junit", parse(from_os_str))]
+        junit: Option<PathBuf>,
+
+        /// Write the step results in JSON format.
+        #[clap(long = "json", parse(from_os_str))]
+        json: Option<PathBuf>,
+    },
+}
+
+impl ScenarioCommand {
+    pub fn apply(&mut self, ctx: &mut Context) -> Result<()> {
+        match self {
+            ScenarioCommand::Run { path, junit, json } => {
+                let scenario = Scenario::load(path)?;
+                let name = path
+                    .file_stem()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| "scenario".to_string());
+
+                run_dove_package_build(ctx)?;
+                let storage_dir = ctx
+                    .project_root_dir
+                    .join("build")
+                    .join("scenario")
+                    .join(&name);
+                let report = run_scenario(ctx, name, &scenario, &storage_dir)?;
+
+                for (idx, step) in report.steps.iter().enumerate() {
+                    println!(
+                        "[{}/{}] {} ... {}",
+                        idx + 1,
+                        report.steps.len(),
+                        step.name,
+                        if step.passed { "ok" } else { "FAILED" }
+                    );
+                    for failure in &step.failures {
+                        println!("    {}", failure.replace('\n', "\n    "));
+                    }
+                }
+                println!(
+                    "Scenario {}: {} passed, {} failed",
+                    report.name, report.passed, report.failed
+                );
+
+                if let Some(path) = junit {
+                    fs::write(path, report.to_junit())?;
+                }
+                if let Some(path) = json {
+                    fs::write(path, serde_json::to_string_pretty(&report)?)?;
+                }
+                ensure!(
+                    report.failed == 0,
+                    "{} of {} steps failed",
+                    report.failed,
+                    report.steps.len()
+                );
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+use reqwest::Url;
+use move_cli::sandbox::utils::on_disk_state_view::OnDiskStateView;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, TypeTag};
+use move_package::compilation::package_layout::CompiledPackageLayout;
+use net::make_net;
+
+use crate::call::parser::parse_tp_param;
+use crate::cmd::deploy::run_dove_package_build;
+use crate::cmd::view::{parse_account, project_resources};
+use crate::context::Context;
+use crate::executor::Executor;
+use crate::fork::load_modules;
+use crate::sandbox::storage_dir;
+use crate::state::{pull_modules, pull_resources, seed_state, StateFile};
+
+/// Sandbox storage state.
+#[derive(Debug, Parser)]
+pub enum StateCommand {
+    /// Write the resources of the state file to the sandbox storage.
+    #[clap(
+        name = "seed",
+        about = "dove state seed [PATH_TO_FILE] [OPTIONS]\n
+    Examples:
+    $ dove state seed fixtures.json
+    $ dove state seed fixtures.json --clean
+    $ dove state seed fixtures.json --sandbox staging-sim
+"
+    )]
+    Seed {
+        /// State file in JSON format.
+        #[clap(name = "PATH_TO_FILE", parse(from_os_str))]
+        path: PathBuf,
+
+        /// Remove the sandbox storage before seeding.
+        #[clap(long = "clean")]
+        clean: bool,
+
+        /// Sandbox name. Default: the project storage.
+        #[clap(long = "sandbox")]
+        sandbox: Option<String>,
+    },
+    /// Copy the account modules and resources from the node to the sandbox storage.
+    #[clap(
+        name = "pull",
+        about = "dove state pull [ADDRESS] [OPTIONS]\n
+    Examples:
+    $ dove state pull 0x1 --modules --url http://127.0.0.1:9933
+    $ dove state pull 0x1 --modules Account Coins --height 1000
+    $ dove state pull Demo --resources Demo::Store::U64
+    $ dove state pull 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY --resources --sandbox staging-sim
+"
+    )]
+    Pull {
+        /// Account address: hex, Move.toml alias or ss58.
+        #[clap(name = "ADDRESS")]
+        address: String,
+
+        /// Module names to pull with their dependencies.
+        /// Without names, the package dependencies published under the address are pulled.
+        /// This is the default if neither modules nor resources are selected.
+        #[clap(long = "modules", min_values = 0)]
+        modules: Option<Vec<String>>,
+
+        /// Resource types to pull. Without types, all resources listed by the node are pulled.
+        /// If the node is not able to list resources, the `key` structs of the project are probed.
+        #[clap(long = "resources", min_values = 0)]
+        resources: Option<Vec<String>>,
+
+        /// The url of the node. Accepts the same backends as `dove view --api`.
+        #[clap(long = "url", default_value = "http://127.0.0.1:9933")]
+        url: Url,
+
+        /// Block number. Default: the latest block.
+        #[clap(long = "height")]
+        height: Option<String>,
+
+        /// Sandbox name. Default: the project storage.
+        #[clap(long = "sandbox")]
+        sandbox: Option<String>,
+    },
+}
+
+impl StateCommand {
+    pub fn apply(&mut self, ctx: &mut Context) -> Result<()> {
+        match self {
+            StateCommand::Seed {
+                path,
+                clean,
+                sandbox,
+            } => {
+                let file = StateFile::load(path)?;
+                run_dove_package_build(ctx)?;
+
+                let storage_dir = storage_dir(ctx, sandbox.as_deref())?;
+                if *clean && storage_dir.exists() {
+                    fs::remove_dir_all(&storage_dir)?;
+                }
+                let executor = Executor::new(ctx, &storage_dir)?;
+                let count = seed_state(ctx, executor.state(), &file)?;
+                println!("{} resources are written to the sandbox storage", count);
+                Ok(())
+            }
+            StateCommand::Pull {
+                address,
+                modules,
+                resources,
+                url,
+                height,
+                sandbox,
+            } => {
+                let addr_map = ctx.address_declarations();
+                let address = parse_account(&addr_map, address)?;
+                // The package dependencies are pulled if nothing is selected.
+                let (modules, resources) = match (modules, resources) {
+                    (None, None) => (Some(vec![]), None),
+                    (modules, resources) => (modules.clone(), resources.clone()),
+                };
+
+                let build_dir = ctx.path_for_build(None, CompiledPackageLayout::Root);
+                let net = make_net(url.clone(), None, &build_dir)?;
+                ensure!(
+                    height.is_none() || net.honors_height(),
+                    "The node backend {} keeps only the latest state. Remove --height",
+                    url
+                );
+
+                let modules = match modules {
+                    Some(names) => {
+                        let module_ids = if names.is_empty() {
+                            run_dove_package_build(ctx)?;
+                            dependency_modules(ctx, address)?
+                        } else {
+                            names
+                                .iter()
+                                .map(|name| {
+                                    Ok(ModuleId::new(address, Identifier::new(name.as_str())?))
+                                })
+                                .collect::<Result<_>>()?
+                        };
+                        pull_modules(net.as_ref(), module_ids, height)?
+                    }
+                    None => Default::default(),
+                };
+                let resources = match resources {
+                    Some(types) if types.is_empty() => {
+                        let (tags, required) = match net.list_resources(&address, height)? {
+                            Some(tags) => (tags, true),
+                            None => {
+                                eprintln!(
+                                    "The node does not support resource listing. \
+                                    Probing resources declared in the project modules."
+                                );
+                                run_dove_package_build(ctx)?;
+                                (project_resources(ctx)?, false)
+                            }
+                        };
+                        pull_resources(net.as_ref(), &address, tags, height, required)?
+                    }
+                    Some(types) => {
+                        let tags = types
+                            .iter()
+                            .map(|tp| match parse_tp_param(&addr_map, tp)? {
+                                TypeTag::Struct(tag) => Ok(tag),
+                                _ => bail!("Resource type expected: {}", tp),
+                            })
+                            .collect::<Result<Vec<_>>>()?;
+                        pull_resources(net.as_ref(), &address, tags, height, true)?
+                    }
+                    None => vec![],
+                };
+
+                let state =
+                    OnDiskStateView::create(&build_dir, &storage_dir(ctx, sandbox.as_deref())?)?;
+                for (id, bytes) in &modules {
+                    state.save_module(id, bytes)?;
+                    println!("module {}::{}", id.address().to_hex_literal(), id.name());
+                }
+                for (tag, blob) in &resources {
+                    state.save_resource(address, tag.clone(), blob)?;
+                    println!("resource {}", tag);
+                }
+                println!(
+                    "{} modules and {} resources are pulled from {} at block {}",
+                    modules.len(),
+                    resources.len(),
+                    url,
+                    height.as_deref().unwrap_or("latest")
+                );
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns the modules of the package dependencies published under the address.
+fn dependency_modules(ctx: &Context, address: AccountAddress) -> Result<Vec<ModuleId>> {
+    let project = load_modules(ctx, Some(ctx.manifest.package.name.as_str()))?;
+    Ok(load_modules(ctx, None)?
+        .into_keys()
+        .filter(|id| id.address() == &address && !project.contains_key(id))
+        .collect())
+}
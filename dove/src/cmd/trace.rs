@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+use move_binary_format::file_format::FunctionDefinitionIndex;
+use move_bytecode_source_map::source_map::SourceMap;
+use move_command_line_common::files::FileHash;
+use lang::bytecode::accessor::{BytecodeAccess, BytecodeRef, BytecodeType};
+use lang::bytecode::info::BytecodeInfo;
+
+use crate::call::bytecode::{DoveBytecode, find_module, load_source_map};
+use crate::cmd::run::find_move_files_in_project;
+use crate::context::Context;
+use crate::vm_trace::{read_trace, trace_script, TraceEntry};
+
+/// Execution traces.
+#[derive(Debug, Parser)]
+pub enum TraceCommand {
+    /// Print the execution trace with the Move source lines.
+    #[clap(
+        name = "view",
+        about = "dove trace view [PATH_TO_FILE] [OPTIONS]\n
+    Examples:
+    $ dove run 'script_name()' --trace trace.txt
+    $ dove trace view trace.txt
+    $ dove trace view trace.txt --tail 20
+"
+    )]
+    View {
+        /// Trace file written by `dove run --trace`.
+        #[clap(name = "PATH_TO_FILE", parse(from_os_str))]
+        path: PathBuf,
+
+        /// Print only the last N instructions.
+        #[clap(long = "tail")]
+        tail: Option<usize>,
+    },
+}
+
+impl TraceCommand {
+    pub fn apply(&mut self, ctx: &mut Context) -> Result<()> {
+        match self {
+            TraceCommand::View { path, tail } => {
+                let entries = read_trace(path)?;
+                let skip = tail
+                    .map(|tail| entries.len().saturating_sub(tail))
+                    .unwrap_or_default();
+
+                let mut sources = SourceResolver::new(ctx, trace_script(path)?);
+                for (idx, entry) in entries.iter().enumerate().skip(skip) {
+                    let location = sources
+                        .location(entry)
+                        .unwrap_or_else(|| "<unknown location>".to_string());
+                    println!(
+                        "{:>6} {}+{} {}\n       at {}",
+                        idx, entry.function, entry.pc, entry.instruction, location
+                    );
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Maps instruction offsets to the Move source lines.
+struct SourceResolver<'a> {
+    ctx: &'a Context,
+    script: Option<PathBuf>,
+    files: HashMap<FileHash, PathBuf>,
+    functions: HashMap<String, Option<(FunctionDefinitionIndex, SourceMap)>>,
+    sources: HashMap<PathBuf, Option<String>>,
+}
+
+impl<'a> SourceResolver<'a> {
+    fn new(ctx: &'a Context, script: Option<PathBuf>) -> SourceResolver<'a> {
+        SourceResolver {
+            ctx,
+            script,
+            files: find_move_files_in_project(&ctx.project_root_dir),
+            functions: Default::default(),
+            sources: Default::default(),
+        }
+    }
+
+    /// Returns `path:line  source` of the instruction.
+    fn location(&mut self, entry: &TraceEntry) -> Option<String> {
+        if !self.functions.contains_key(&entry.function) {
+            let function = self.load_function(entry).ok().flatten();
+            self.functions.insert(entry.function.clone(), function);
+        }
+        let (index, source_map) = self.functions.get(&entry.function)?.as_ref()?;
+        let loc = source_map.get_code_location(*index, entry.pc).ok()?;
+
+        let path = self.files.get(&loc.file_hash())?.clone();
+        let source = self
+            .sources
+            .entry(path.clone())
+            .or_insert_with(|| fs::read_to_string(&path).ok())
+            .as_ref()?;
+        let start = (loc.start() as usize).min(source.len());
+        let line = source[..start].matches('\n').count() + 1;
+        let text = source.lines().nth(line - 1).unwrap_or_default().trim();
+
+        let path = path
+            .strip_prefix(&self.ctx.project_root_dir)
+            .unwrap_or(&path);
+        Some(format!("{}:{}  {}", path.display(), line, text))
+    }
+
+    fn load_function(
+        &self,
+        entry: &TraceEntry,
+    ) -> Result<Option<(FunctionDefinitionIndex, SourceMap)>> {
+        let bi = match entry.module_id() {
+            Some(module_id) => find_module(self.ctx, &module_id)?,
+            None => match &self.script {
+                Some(script) => DoveBytecode::new(self.ctx)
+                    .load(BytecodeRef(
+                        script.to_string_lossy().to_string(),
+                        BytecodeType::Script,
+                    ))?
+                    .map(BytecodeInfo::from),
+                None => None,
+            },
+        };
+        let bi = match bi {
+            Some(bi) => bi,
+            None => return Ok(None),
+        };
+
+        let index = if bi.is_module() {
+            match bi.find_any_function(entry.function_name()) {
+                Some(func) => func.index,
+                None => return Ok(None),
+            }
+        } else {
+            0
+        };
+        Ok(Some((
+            FunctionDefinitionIndex(index),
+            load_source_map(&bi)?,
+        )))
+    }
+}
@@ -0,0 +1,396 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Error, Result};
+use clap::Parser;
+use serde::Serialize;
+use url::Url;
+use move_binary_format::file_format::{CompiledScript, empty_module};
+use move_core_types::language_storage::TypeTag;
+use lang::bytecode::accessor::{Bytecode, BytecodeRef, BytecodeType};
+use lang::bytecode::info::{BytecodeInfo, Type};
+use lang::bytecode::{find, SearchParams};
+
+use crate::call::bytecode::{DoveBytecode, parameter_names};
+use crate::call::decode::{decode_value, value_to_json, value_to_string};
+use crate::call::model::{Call, Signer, Transaction};
+use crate::context::Context;
+use crate::publish::{Sign, SignParamsCmd, submit};
+
+/// Transaction files.
+#[derive(Debug, Parser)]
+pub enum TxCommand {
+    /// Decode a transaction file and print its content.
+    #[clap(
+        name = "inspect",
+        about = "dove tx inspect [FILE_NAME|PATH_TO_FILE] [OPTIONS]\n
+    Examples:
+    $ dove tx inspect build/Project/transaction/main.mvt
+    $ dove tx inspect Module_function --json
+"
+    )]
+    Inspect {
+        /// Transaction file name in `build/<package>/transaction` or path to the file.
+        #[clap(name = "FILE_NAME|PATH_TO_FILE")]
+        file: String,
+
+        /// Package name. The default package is used if not specified.
+        #[clap(long = "package", short = 'c')]
+        package: Option<String>,
+
+        /// Print the transaction in JSON format.
+        #[clap(long = "json", short = 'j')]
+        json: bool,
+    },
+
+    /// Sign a transaction, module or package without connection to the node.
+    #[clap(
+        name = "sign",
+        about = "dove tx sign [FILE_NAME|PATH_TO_FILE] [OPTIONS]\n
+    Examples:
+    $ dove tx sign Module_function --account WALLET_KEY --gas 300 --meta chain.json
+    $ dove tx sign build/Project/bundles/Project.pac --secret --gas 400 --nonce 3 \\
+        --genesis-hash 0x3fd7...5baf --spec-version 100 --tx-version 1
+"
+    )]
+    Sign {
+        /// Transaction file name in `build/<package>/transaction` or path to the `mvt`, `mv` or `pac` file.
+        #[clap(name = "FILE_NAME|PATH_TO_FILE")]
+        file: String,
+
+        /// Package name. The default package is used if not specified.
+        #[clap(long = "package", short = 'c')]
+        package: Option<String>,
+
+        /// Path to the output file. Default: `<FILE>.signed` next to the input file.
+        #[clap(long = "output", short = 'o')]
+        output: Option<PathBuf>,
+
+        #[clap(flatten)]
+        params: SignParamsCmd,
+    },
+
+    /// Submit a signed extrinsic to the node.
+    #[clap(
+        name = "submit",
+        about = "dove tx submit [PATH_TO_FILE] [OPTIONS]\n
+    Examples:
+    $ dove tx submit build/Project/transaction/main.mvt.signed --url ws://127.0.0.1:9944
+"
+    )]
+    Submit {
+        /// Path to the signed extrinsic file.
+        #[clap(name = "PATH_TO_FILE")]
+        file: PathBuf,
+
+        /// The url of the substrate node to query
+        #[clap(
+            long = "url",
+            short,
+            parse(try_from_str),
+            default_value = "ws://localhost:9944"
+        )]
+        url_to_node: Url,
+    },
+}
+
+impl TxCommand {
+    pub fn apply(&mut self, ctx: &mut Context) -> Result<()> {
+        match self {
+            TxCommand::Inspect {
+                file,
+                package,
+                json,
+            } => {
+                let path = tx_path(ctx, file, package.take())?;
+                let tx: Transaction = bcs::from_bytes(&fs::read(&path)?)
+                    .map_err(|err| anyhow!("Failed to decode transaction {:?}: {}", path, err))?;
+                let info = TxInfo::new(ctx, tx)?;
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&info)?);
+                } else {
+                    print!("{}", info.to_text());
+                }
+                Ok(())
+            }
+            TxCommand::Sign {
+                file,
+                package,
+                output,
+                params,
+            } => {
+                let path = tx_path(ctx, file, package.take())?;
+                let output = output.take().unwrap_or_else(|| {
+                    let mut name = path.as_os_str().to_owned();
+                    name.push(".signed");
+                    PathBuf::from(name)
+                });
+
+                let extrinsic = Sign::try_from((&*params, path))?.apply()?;
+                fs::write(&output, &extrinsic)?;
+                println!("Signed extrinsic: {:?}", output);
+                Ok(())
+            }
+            TxCommand::Submit { file, url_to_node } => {
+                let hash = submit(url_to_node, file)?;
+                println!("Hash: {}", hash);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns path to the transaction file.
+/// `file` can be a path or a file name in the transaction folder of the package.
+pub(crate) fn tx_path(ctx: &Context, file: &str, package: Option<String>) -> Result<PathBuf> {
+    let path = PathBuf::from(file);
+    if path.is_file() {
+        return Ok(path);
+    }
+
+    let mut path = ctx.tx_output_path(package).join(file);
+    if path.extension().is_none() {
+        path.set_extension("mvt");
+    }
+    ensure!(path.is_file(), "Transaction file {:?} not found", path);
+    Ok(path)
+}
+
+/// Decoded transaction.
+#[derive(Debug, Serialize)]
+pub struct TxInfo {
+    /// Transaction signers.
+    pub signers: Vec<String>,
+    /// Call target.
+    pub call: CallInfo,
+    /// Type arguments.
+    pub type_args: Vec<String>,
+    /// Call arguments.
+    pub args: Vec<ArgInfo>,
+}
+
+/// Call target.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CallInfo {
+    /// Script.
+    Script {
+        /// Script name if it is found in the build directory.
+        name: Option<String>,
+        /// Script bytecode size.
+        size: usize,
+    },
+    /// Function with script visibility.
+    ScriptFunction {
+        /// Module address.
+        address: String,
+        /// Module name.
+        module: String,
+        /// Function name.
+        function: String,
+    },
+}
+
+/// Decoded call argument.
+#[derive(Debug, Serialize)]
+pub struct ArgInfo {
+    /// Parameter name.
+    pub name: Option<String>,
+    /// Parameter type. Unknown if the function signature was not found.
+    #[serde(rename = "type")]
+    pub tp: Option<String>,
+    /// Decoded value. BCS bytes in hex if the signature was not found.
+    pub value: serde_json::Value,
+    /// Value in Move-like text format.
+    #[serde(skip)]
+    pub text: String,
+}
+
+impl TxInfo {
+    /// Decodes the transaction using function signatures from the build directory.
+    pub fn new(ctx: &Context, tx: Transaction) -> Result<TxInfo, Error> {
+        let tx = tx.inner();
+        let signers = tx
+            .signers
+            .iter()
+            .map(|signer| match signer {
+                Signer::Root => "root".to_string(),
+                Signer::Placeholder => "_".to_string(),
+                Signer::Name(name) => name.to_string(),
+            })
+            .collect();
+        let type_args = tx.type_args.iter().map(|tag| tag.to_string()).collect();
+
+        let access = DoveBytecode::new(ctx);
+        let (call, bytecode) = match tx.call {
+            Call::Script { code } => {
+                let bytecode = find_script(&access, &code)?;
+                let call = CallInfo::Script {
+                    name: bytecode.as_ref().map(|bi| bi.name()),
+                    size: code.len(),
+                };
+                let bytecode = bytecode.or_else(|| {
+                    CompiledScript::deserialize(&code).ok().map(|script| {
+                        BytecodeInfo::from(Bytecode::Script(
+                            "main".to_string(),
+                            script,
+                            Box::new(empty_module()),
+                            BytecodeRef(String::new(), BytecodeType::Script),
+                        ))
+                    })
+                });
+                (call, bytecode.map(|bi| (bi.name(), bi)))
+            }
+            Call::ScriptFunction {
+                mod_address,
+                mod_name,
+                func_name,
+            } => {
+                let bytecode = find(
+                    access.clone(),
+                    SearchParams {
+                        tp: Some(BytecodeType::Module),
+                        package: None,
+                        name: Some(mod_name.as_str()),
+                    },
+                )?
+                .filter_map(|info| info.ok())
+                .find(|info| {
+                    info.address() == Some(mod_address) && info.name() == mod_name.as_str()
+                });
+                let call = CallInfo::ScriptFunction {
+                    address: mod_address.to_hex_literal(),
+                    module: mod_name.into_string(),
+                    function: func_name.to_string(),
+                };
+                (call, bytecode.map(|bi| (func_name.into_string(), bi)))
+            }
+        };
+
+        let function =
+            bytecode.and_then(|(name, bi)| bi.find_script_function(&name).map(|func| (bi, func)));
+        let args = match function {
+            Some((bi, func)) => {
+                let names = parameter_names(&bi, &func).ok();
+                decode_args(&func.parameters, names, &tx.type_args, &tx.args, &access)?
+            }
+            None => {
+                eprintln!(
+                    "Function signature not found in the build directory, \
+                    the arguments are not decoded. Run `dove build` and try again."
+                );
+                tx.args
+                    .iter()
+                    .map(|arg| {
+                        let hex = format!("0x{}", hex::encode(arg));
+                        ArgInfo {
+                            name: None,
+                            tp: None,
+                            value: serde_json::Value::String(hex.clone()),
+                            text: hex,
+                        }
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(TxInfo {
+            signers,
+            call,
+            type_args,
+            args,
+        })
+    }
+
+    /// Renders the transaction in text format.
+    pub fn to_text(&self) -> String {
+        let mut text = match &self.call {
+            CallInfo::Script { name, size } => format!(
+                "Script: {} ({} bytes)\n",
+                name.as_deref().unwrap_or("<unknown>"),
+                size
+            ),
+            CallInfo::ScriptFunction {
+                address,
+                module,
+                function,
+            } => format!("Function: {}::{}::{}\n", address, module, function),
+        };
+        text.push_str(&format!("Signers: [{}]\n", self.signers.join(", ")));
+        text.push_str(&format!(
+            "Type arguments: [{}]\n",
+            self.type_args.join(", ")
+        ));
+        text.push_str("Arguments:\n");
+        for (idx, arg) in self.args.iter().enumerate() {
+            let name = arg.name.clone().unwrap_or_else(|| format!("#{}", idx));
+            match &arg.tp {
+                Some(tp) => text.push_str(&format!("    {}: {} = {}\n", name, tp, arg.text)),
+                None => text.push_str(&format!("    {} = {}\n", name, arg.text)),
+            }
+        }
+        text
+    }
+}
+
+/// Finds the script with the same bytecode in the build directory.
+fn find_script(access: &DoveBytecode, code: &[u8]) -> Result<Option<BytecodeInfo>, Error> {
+    Ok(find(
+        access.clone(),
+        SearchParams {
+            tp: Some(BytecodeType::Script),
+            package: None,
+            name: None,
+        },
+    )?
+    .filter_map(|info| info.ok())
+    .find_map(|info| {
+        let mut buff = Vec::new();
+        info.serialize(&mut buff).ok()?;
+        if buff == code {
+            Some(info)
+        } else {
+            None
+        }
+    }))
+}
+
+fn decode_args(
+    parameters: &[Type],
+    names: Option<Vec<String>>,
+    type_args: &[TypeTag],
+    args: &[Vec<u8>],
+    access: &DoveBytecode,
+) -> Result<Vec<ArgInfo>, Error> {
+    let signers_count = parameters
+        .iter()
+        .take_while(|tp| **tp == Type::Signer)
+        .count();
+    let parameters = &parameters[signers_count..];
+    ensure!(
+        parameters.len() == args.len(),
+        "The function accepts {} parameters, the transaction contains {}",
+        parameters.len(),
+        args.len()
+    );
+
+    let type_args = type_args.iter().map(Type::from).collect::<Vec<_>>();
+    parameters
+        .iter()
+        .zip(args)
+        .enumerate()
+        .map(|(idx, (tp, arg))| {
+            let tp = tp.instantiate(&type_args);
+            let value = decode_value(&tp, arg, access)
+                .map_err(|err| anyhow!("Failed to decode argument #{}: {}", idx, err))?;
+            Ok(ArgInfo {
+                name: names
+                    .as_ref()
+                    .and_then(|names| names.get(signers_count + idx).cloned()),
+                tp: Some(tp.to_string()),
+                value: value_to_json(&tp, value.clone(), access)?,
+                text: value_to_string(&tp, value, access)?,
+            })
+        })
+        .collect()
+}
@@ -4,15 +4,30 @@ use clap::Parser;
 use log::{error, info};
 use reqwest::Url;
 
-use move_core_types::language_storage::TypeTag;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_package::compilation::package_layout::CompiledPackageLayout;
 use move_package::source_package::parsed_manifest::{AddressDeclarations, NamedAddress};
 
+use lang::bytecode::accessor::BytecodeType;
+use lang::bytecode::{find, SearchParams};
 use lang::ss58::ss58_to_address;
+use move_resource_viewer::AnnotatedMoveStruct;
+use resource_viewer::diff::ResourceDiff;
 use resource_viewer::ser;
-use net::{make_net, NetView};
+use net::cache::CachedNet;
+use net::fixture::RecordingNet;
+use net::{make_local_net, make_net, Net, NetKind, NetView};
 
 use crate::context::Context;
+use crate::dot_move_folder;
+use crate::call::bytecode::DoveBytecode;
 use crate::call::parser::parse_type_param;
+use crate::sandbox::storage_dir;
+
+/// Directory of the node responses cache in `~/.move`.
+const NET_CACHE_DIR: &str = "net_cache";
 
 /// Move Resource Viewer
 #[derive(Parser, Debug)]
@@ -22,14 +37,23 @@ use crate::call::parser::parse_type_param;
     $ dove view Account::Store::U64 --api http://127.0.0.1:9933
     $ dove view Account::Store::U64 --api http://127.0.0.1:9933 --json
     $ dove view 0x1::Account::Balance<0x1::Coins::ETH> --api http://127.0.0.1:9933 --json --output PATH/SAVE.json
+    $ dove view Account::Store::U64 --local
+    $ dove view Account --local
+    $ dove view Account::Store::U64 --api file:///PATH/fixture.json
+    $ dove view Account::Store::U64 --api http://127.0.0.1:9933 --record PATH/fixture.json
+    $ dove view Account::Store::U64 --api http://127.0.0.1:1317 --net-kind dnode
+    $ dove view Account::Store::U64 --diff-from 100 --diff-to 105 --json
+    $ dove view 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY --api http://127.0.0.1:9933
 ")]
 pub struct View {
     #[clap(
         display_order = 1,
         help = "Fully qualified type description in a form of ADDRESS::MODULE::TYPE_NAME<GENERIC_PARAMS> \n\
+            or an account address to list all of its resources. \n\
             Examples: \n\
             Account::Store::U64 \n\
-            0x1::Account::Balance<0x1::Coins::ETH>"
+            0x1::Account::Balance<0x1::Coins::ETH> \n\
+            Account"
     )]
     query: String,
 
@@ -37,10 +61,19 @@ pub struct View {
         long,
         default_value = "http://127.0.0.1:9933",
         display_order = 2,
-        help = "The url of the substrate node to query. HTTP or HTTPS only"
+        help = "The url of the node to query. The backend is selected by the url scheme: \n\
+            http(s) - pontem node, dnode+http(s) - dfinance node, \n\
+            file - sandbox storage directory or fixture file"
     )]
     api: Url,
 
+    #[clap(
+        long = "net-kind",
+        display_order = 10,
+        help = "Node backend. Overrides the backend selected by the url scheme"
+    )]
+    net_kind: Option<NetKind>,
+
     #[clap(long, short, display_order = 3, help = "Sets output format to JSON")]
     json: bool,
 
@@ -56,6 +89,63 @@ pub struct View {
 
     #[clap(long, short, display_order = 6, help = "Block number")]
     height: Option<String>,
+
+    #[clap(
+        long,
+        display_order = 7,
+        conflicts_with_all = &["api", "height", "net_kind"],
+        help = "Query the local sandbox storage written by `dove run` instead of the node"
+    )]
+    local: bool,
+
+    #[clap(
+        long = "sandbox",
+        display_order = 13,
+        requires = "local",
+        help = "Sandbox name. Default: the project storage"
+    )]
+    sandbox: Option<String>,
+
+    #[clap(
+        long = "diff-from",
+        display_order = 8,
+        requires = "diff_to",
+        conflicts_with_all = &["height", "local"],
+        help = "Block number of the original state. Prints the resource diff between two blocks"
+    )]
+    diff_from: Option<String>,
+
+    #[clap(
+        long = "diff-to",
+        display_order = 9,
+        requires = "diff_from",
+        help = "Block number of the changed state"
+    )]
+    diff_to: Option<String>,
+
+    #[clap(
+        long = "no-cache",
+        display_order = 11,
+        help = "Do not use the cache of node responses"
+    )]
+    no_cache: bool,
+
+    #[clap(
+        long = "clear-cache",
+        display_order = 12,
+        conflicts_with = "no_cache",
+        help = "Clear the cache of node responses before the query"
+    )]
+    clear_cache: bool,
+
+    #[clap(
+        long = "record",
+        display_order = 14,
+        parse(from_os_str),
+        help = "Record the node responses to a fixture file. \n\
+            Replay it with `--api file://<PATH>`"
+    )]
+    record: Option<PathBuf>,
 }
 
 impl View {
@@ -65,36 +155,37 @@ impl View {
         }
 
         let height = self.height.clone();
-        let net = make_net(self.api.clone())?;
         let address_map = ctx.manifest.addresses.clone().unwrap_or_default();
 
+        if !self.query.contains("::") {
+            ensure!(
+                self.diff_from.is_none(),
+                "Diff is supported only for a single resource"
+            );
+            let address = parse_account_query(&address_map, &self.query)?;
+            let net = self.make_net(ctx)?;
+            return self.view_account(ctx, net, address, height);
+        }
+        let net = self.make_net(ctx)?;
+
         if !self.query.starts_with("0x") {
             if let Some(pos) = self.query.find("::") {
                 let name_address = &self.query[..pos];
-                let address = address_map
-                    .get(&NamedAddress::from(name_address))
-                    .map(|acc| {
-                        acc.ok_or_else(|| {
-                            anyhow!(
-                                "In Move.toml address not assigned to alias {}",
-                                name_address
-                            )
-                        })
-                    })
-                    .unwrap_or_else(|| ss58_to_address(name_address))?;
-
+                let address = parse_account(&address_map, name_address)?;
                 self.query = format!("{}{}", address.to_hex_literal(), &self.query[pos..]);
             }
         }
         let query = parse_query(&address_map, &self.query)?;
 
         match query {
+            TypeTag::Struct(st) if self.diff_from.is_some() => self.view_diff(net, st),
             TypeTag::Struct(st) => {
                 let addr = st.address;
 
                 net.get_resource(&addr, &st, &height)
                     .map(|resp| {
                         let view = NetView::new(net, height);
+                        prefetch(&view, vec![st.clone()]);
                         if let Some(bytes_for_block) = resp {
                             // Internally produce FatStructType (with layout) for StructTag by
                             // resolving & de-.. entire deps-chain.
@@ -127,6 +218,201 @@ impl View {
             _ => bail!("Unsupported type {}", query),
         }
     }
+
+    /// Prints the field-level diff of the resource between two blocks.
+    fn view_diff(&self, net: Box<dyn Net>, tag: StructTag) -> anyhow::Result<()> {
+        ensure!(
+            net.honors_height(),
+            "The node backend keeps only the latest state. Diff between blocks is not available."
+        );
+        let from_height = self.diff_from.clone();
+        let to_height = self.diff_to.clone();
+
+        let from = net.get_resource(&tag.address, &tag, &from_height)?;
+        let to = net.get_resource(&tag.address, &tag, &to_height)?;
+        ensure!(
+            from.is_some() || to.is_some(),
+            "Resource not found at both blocks"
+        );
+
+        let mut view = NetView::new(net, from_height.clone());
+        prefetch(&view, vec![tag.clone()]);
+        let from = match from {
+            Some(bytes_for_block) => Some((
+                bytes_for_block.1,
+                move_resource_viewer::MoveValueAnnotator::new(&view)
+                    .view_resource(&tag, &bytes_for_block.0)?,
+            )),
+            None => None,
+        };
+        view.set_block(to_height.clone());
+        prefetch(&view, vec![tag.clone()]);
+        let to = match to {
+            Some(bytes_for_block) => Some((
+                bytes_for_block.1,
+                move_resource_viewer::MoveValueAnnotator::new(&view)
+                    .view_resource(&tag, &bytes_for_block.0)?,
+            )),
+            None => None,
+        };
+
+        let height = |resource: &Option<(String, AnnotatedMoveStruct)>, block: Option<String>| {
+            resource
+                .as_ref()
+                .map(|(height, _)| height.clone())
+                .or(block)
+                .unwrap_or_default()
+        };
+        let diff = ResourceDiff::new(
+            tag.to_string(),
+            (
+                height(&from, from_height),
+                from.as_ref().map(|(_, result)| result),
+            ),
+            (
+                height(&to, to_height),
+                to.as_ref().map(|(_, result)| result),
+            ),
+        );
+
+        let result = if self.json {
+            serde_json::ser::to_string_pretty(&diff)?
+        } else {
+            diff.to_string()
+        };
+        write_output(self.output.as_deref(), &result, "result");
+        Ok(())
+    }
+
+    /// Prints all resources stored under the address.
+    fn view_account(
+        &self,
+        ctx: &Context,
+        net: Box<dyn Net>,
+        address: AccountAddress,
+        height: Option<String>,
+    ) -> anyhow::Result<()> {
+        let tags = match net.list_resources(&address, &height)? {
+            Some(tags) => tags,
+            None => {
+                eprintln!(
+                    "The node does not support resource listing. \
+                    Probing resources declared in the project modules."
+                );
+                project_resources(ctx)?
+            }
+        };
+
+        let mut resources = Vec::new();
+        for tag in tags {
+            if let Some(bytes_for_block) = net.get_resource(&address, &tag, &height)? {
+                resources.push((tag, bytes_for_block));
+            }
+        }
+        if resources.is_empty() {
+            eprintln!("No resources found under {}", address.to_hex_literal());
+        }
+
+        let view = NetView::new(net, height);
+        prefetch(
+            &view,
+            resources.iter().map(|(tag, _)| tag.clone()).collect(),
+        );
+        let annotator = move_resource_viewer::MoveValueAnnotator::new(&view);
+        let resources = resources
+            .into_iter()
+            .map(|(tag, bytes_for_block)| {
+                annotator
+                    .view_resource(&tag, &bytes_for_block.0)
+                    .map(|result| ser::AnnotatedMoveStructWrapper {
+                        height: bytes_for_block.1,
+                        result,
+                    })
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let result = if self.json {
+            serde_json::ser::to_string_pretty(&resources)?
+        } else {
+            resources
+                .iter()
+                .map(|resource| format!("{}", resource.result))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        write_output(self.output.as_deref(), &result, "result");
+        Ok(())
+    }
+}
+
+impl View {
+    fn make_net(&self, ctx: &Context) -> anyhow::Result<Box<dyn Net>> {
+        let net = self.make_backend(ctx)?;
+        match &self.record {
+            Some(path) => Ok(Box::new(RecordingNet::new(net, path)?)),
+            None => Ok(net),
+        }
+    }
+
+    fn make_backend(&self, ctx: &Context) -> anyhow::Result<Box<dyn Net>> {
+        if self.local {
+            make_local_net(
+                &ctx.path_for_build(None, CompiledPackageLayout::Root),
+                &storage_dir(ctx, self.sandbox.as_deref())?,
+            )
+        } else {
+            let net = make_net(
+                self.api.clone(),
+                self.net_kind,
+                &ctx.path_for_build(None, CompiledPackageLayout::Root),
+            )?;
+            let kind = match self.net_kind {
+                Some(kind) => kind,
+                None => NetKind::from_url(&self.api)?,
+            };
+            if self.no_cache || !matches!(kind, NetKind::Pontem | NetKind::Dnode) {
+                return Ok(net);
+            }
+
+            // Responses for pinned blocks are cached on disk per node.
+            let node = format!(
+                "{}_{}",
+                self.api.host_str().unwrap_or_default(),
+                self.api.port_or_known_default().unwrap_or_default()
+            );
+            let net =
+                CachedNet::new(net, Some(dot_move_folder()?.join(NET_CACHE_DIR).join(node)));
+            if self.clear_cache {
+                net.invalidate()?;
+            }
+            Ok(Box::new(net))
+        }
+    }
+}
+
+/// Loads modules of the resource types with their dependencies in batches.
+/// Modules that failed to prefetch are loaded on demand by the annotator.
+fn prefetch(view: &NetView, tags: Vec<StructTag>) {
+    let mut modules = Vec::new();
+    let mut tags = tags;
+    while let Some(tag) = tags.pop() {
+        modules.push(tag.module_id());
+        for tp in tag.type_params {
+            collect_struct_tags(tp, &mut tags);
+        }
+    }
+
+    if let Err(err) = view.prefetch_modules(modules) {
+        info!("Failed to prefetch modules: {}", err);
+    }
+}
+
+fn collect_struct_tags(tp: TypeTag, tags: &mut Vec<StructTag>) {
+    match tp {
+        TypeTag::Struct(tag) => tags.push(tag),
+        TypeTag::Vector(tp) => collect_struct_tags(*tp, tags),
+        _ => {}
+    }
 }
 
 fn produce_json_schema(path: &Path) {
@@ -149,6 +435,68 @@ fn write_output(path: Option<&Path>, result: &str, name: &str) {
     }
 }
 
+/// Resolves an account given as a hex address, a Move.toml alias or an ss58 address.
+pub(crate) fn parse_account(
+    addr_map: &AddressDeclarations,
+    account: &str,
+) -> Result<AccountAddress, Error> {
+    if account.starts_with("0x") {
+        return AccountAddress::from_hex_literal(account)
+            .map_err(|err| anyhow!("Invalid address {}: {}", account, err));
+    }
+
+    addr_map
+        .get(&NamedAddress::from(account))
+        .map(|acc| {
+            acc.ok_or_else(|| anyhow!("In Move.toml address not assigned to alias {}", account))
+        })
+        .unwrap_or_else(|| ss58_to_address(account))
+}
+
+/// Parses a query without `::`: an address, an alias from Move.toml or an ss58 address.
+fn parse_account_query(
+    addr_map: &AddressDeclarations,
+    query: &str,
+) -> Result<AccountAddress, Error> {
+    if query.starts_with("0x") || addr_map.contains_key(&NamedAddress::from(query)) {
+        return parse_account(addr_map, query);
+    }
+    ss58_to_address(query)
+        .map_err(|_| anyhow!("Expected account or resource type. Actual: {}", query))
+}
+
+/// Returns tags of the non-generic `key` structs declared in the project modules.
+pub(crate) fn project_resources(ctx: &Context) -> Result<Vec<StructTag>, Error> {
+    let modules = find(
+        DoveBytecode::new(ctx),
+        SearchParams {
+            tp: Some(BytecodeType::Module),
+            package: None,
+            name: None,
+        },
+    )?;
+
+    let mut tags = Vec::new();
+    for info in modules {
+        let info = info?;
+        let address = match info.address() {
+            Some(address) => address,
+            None => continue,
+        };
+        for name in info.resource_names() {
+            tags.push(StructTag {
+                address,
+                module: Identifier::new(info.name())?,
+                name: Identifier::new(name)?,
+                type_params: vec![],
+            });
+        }
+    }
+    tags.sort();
+    tags.dedup();
+    Ok(tags)
+}
+
 /// Query parsing
 ///     addr_map:&AddressDeclarations - To check alias addresses and replace with a hexadecimal address
 ///     qyery - Query string for parsing
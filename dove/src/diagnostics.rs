@@ -0,0 +1,92 @@
+use std::fs;
+
+use anyhow::Result;
+use codespan_reporting::diagnostic::{Diagnostic, Label};
+use codespan_reporting::files::SimpleFiles;
+use codespan_reporting::term;
+use codespan_reporting::term::termcolor::{ColorChoice, StandardStream};
+use move_binary_format::errors::{Location, VMError};
+use move_bytecode_source_map::source_map::SourceMap;
+use move_core_types::vm_status::StatusCode;
+
+use crate::call::bytecode::{find_module, load_source_map};
+use crate::cmd::run::find_move_files_in_project;
+use crate::context::Context;
+
+/// Prints the execution error as a diagnostic pointing to the Move source line.
+/// `script` is the source map of the executed script.
+/// Returns `false` if the source location of the error is unknown.
+pub fn emit_vm_error(ctx: &Context, err: &VMError, script: Option<&SourceMap>) -> Result<bool> {
+    let (function, offset) = match err.offsets().first() {
+        Some(offset) => *offset,
+        None => return Ok(false),
+    };
+    let module_map;
+    let source_map = match err.location() {
+        Location::Module(id) => match find_module(ctx, id)? {
+            Some(bi) => {
+                module_map = load_source_map(&bi)?;
+                &module_map
+            }
+            None => return Ok(false),
+        },
+        Location::Script => match script {
+            Some(script) => script,
+            None => return Ok(false),
+        },
+        Location::Undefined => return Ok(false),
+    };
+    let loc = source_map.get_code_location(function, offset)?;
+
+    let path = match find_move_files_in_project(&ctx.project_root_dir).remove(&loc.file_hash()) {
+        Some(path) => path,
+        None => return Ok(false),
+    };
+    let name = path
+        .strip_prefix(&ctx.project_root_dir)
+        .unwrap_or(&path)
+        .display()
+        .to_string();
+    let mut files = SimpleFiles::new();
+    let file_id = files.add(name, fs::read_to_string(&path)?);
+
+    let label = Label::primary(file_id, loc.start() as usize..loc.end() as usize);
+    let diagnostic = match (err.major_status(), err.sub_status(), err.location()) {
+        (StatusCode::ABORTED, Some(code), location) => {
+            let mut notes = vec![];
+            if let Location::Module(id) = location {
+                notes.push(format!("in module {}", id));
+                if let Some(explanation) = ctx.error_descriptions.get_explanation(id, code) {
+                    notes.push(format!(
+                        "reason: {}: {}",
+                        explanation.reason.code_name, explanation.reason.code_description
+                    ));
+                    notes.push(format!(
+                        "category: {}: {}",
+                        explanation.category.code_name, explanation.category.code_description
+                    ));
+                }
+            }
+            Diagnostic::error()
+                .with_message(format!("execution aborted with code {}", code))
+                .with_labels(vec![label.with_message("aborted here")])
+                .with_notes(notes)
+        }
+        (status, _, _) => Diagnostic::error()
+            .with_message(format!("execution failed with status {:?}", status))
+            .with_labels(vec![label.with_message("failed here")]),
+    };
+
+    let color = if atty::is(atty::Stream::Stdout) {
+        ColorChoice::Auto
+    } else {
+        ColorChoice::Never
+    };
+    term::emit(
+        &mut StandardStream::stdout(color),
+        &term::Config::default(),
+        &files,
+        &diagnostic,
+    )?;
+    Ok(true)
+}
@@ -0,0 +1,240 @@
+use std::path::Path;
+
+use anyhow::Result;
+use move_binary_format::errors::{Location, VMError};
+use move_cli::sandbox::utils::on_disk_state_view::OnDiskStateView;
+use move_cli::sandbox::utils::{get_gas_status, PackageContext};
+use move_core_types::effects::{ChangeSet, Event};
+use move_core_types::identifier::{IdentStr, Identifier};
+use move_core_types::language_storage::ModuleId;
+use move_core_types::resolver::MoveResolver;
+use move_core_types::value::MoveValue;
+use move_core_types::vm_status::StatusCode;
+use move_package::BuildConfig;
+use move_vm_runtime::move_vm::MoveVM;
+
+use crate::call::model::{EnrichedTransaction, Transaction};
+use crate::context::Context;
+
+/// Executor of local transactions.
+/// By default it runs on top of the move-cli sandbox storage.
+pub struct Executor<'a, S = OnDiskStateView> {
+    ctx: &'a Context,
+    state: S,
+}
+
+/// Changes produced by a successful execution.
+pub struct Effects {
+    /// Resources changes.
+    pub change_set: ChangeSet,
+    /// Emitted events.
+    pub events: Vec<Event>,
+}
+
+/// Local execution result.
+pub struct ExecutionResult {
+    /// Transaction effects or the VM error if the execution failed.
+    pub effects: Result<Effects, VMError>,
+    /// Gas used by the execution.
+    pub gas_used: u64,
+    /// BCS-encoded values returned by a module function.
+    pub return_values: Vec<Vec<u8>>,
+}
+
+impl<'a> Executor<'a> {
+    /// Opens the sandbox storage in `storage_dir` and preloads it with the package dependencies.
+    pub fn new(ctx: &'a Context, storage_dir: &Path) -> Result<Executor<'a>> {
+        let package = PackageContext::new(&ctx.project_root_dir, &build_config(ctx))?;
+        let state = package.prepare_state(storage_dir)?;
+        Ok(Executor { ctx, state })
+    }
+
+    /// Writes the transaction effects to the sandbox storage.
+    pub fn commit(&self, effects: Effects) -> Result<()> {
+        // The storage can't unpublish modules. Checked upfront, so nothing is written on failure.
+        for (addr, account) in &effects.change_set.accounts {
+            for (name, module) in &account.modules {
+                ensure!(
+                    module.is_some(),
+                    "Module {}::{} can't be deleted from the sandbox storage",
+                    addr.to_hex_literal(),
+                    name
+                );
+            }
+        }
+
+        for (addr, account) in effects.change_set.accounts {
+            for (name, module) in account.modules {
+                if let Some(blob) = module {
+                    self.state.save_module(&ModuleId::new(addr, name), &blob)?;
+                }
+            }
+            for (tag, resource) in account.resources {
+                match resource {
+                    Some(blob) => self.state.save_resource(addr, tag, &blob)?,
+                    None => self.state.delete_resource(addr, tag)?,
+                }
+            }
+        }
+
+        for (key, seq_num, tp, data) in effects.events {
+            self.state.save_event(&key, seq_num, tp, data)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, S: MoveResolver> Executor<'a, S> {
+    /// Creates an executor on top of the given state.
+    pub fn with_state(ctx: &'a Context, state: S) -> Executor<'a, S> {
+        Executor { ctx, state }
+    }
+
+    /// State of the executor.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Executes the local transaction.
+    /// Nothing is written to the storage, use [Executor::commit] to save the effects.
+    pub fn execute(&self, tx: EnrichedTransaction, gas_budget: u64) -> Result<ExecutionResult> {
+        let (bi, args, type_args, func_name, signers) = match tx {
+            EnrichedTransaction::Local {
+                bi,
+                args,
+                type_tag,
+                func_name,
+                signers,
+            } => (bi, args, type_tag, func_name, signers),
+            EnrichedTransaction::Global { .. } => {
+                bail!("A transaction for local execution was expected")
+            }
+        };
+
+        let args = signers
+            .iter()
+            .map(|addr| {
+                MoveValue::Signer(*addr)
+                    .simple_serialize()
+                    .ok_or_else(|| anyhow!("Failed to serialize signer {}", addr))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .chain(Transaction::make_args(args)?)
+            .collect();
+
+        let vm =
+            MoveVM::new(self.ctx.native_functions.clone()).map_err(|err| err.into_vm_status())?;
+        let mut gas_status = get_gas_status(&self.ctx.cost_table, Some(gas_budget))?;
+        let mut session = vm.new_session(&self.state);
+
+        let res = match func_name {
+            Some(func_name) => {
+                let address = bi
+                    .address()
+                    .ok_or_else(|| anyhow!("Module address is expected"))?;
+                let module_id = ModuleId::new(address, Identifier::new(bi.name())?);
+                if bi.find_script_function(&func_name).is_some() {
+                    session
+                        .execute_script_function(
+                            &module_id,
+                            IdentStr::new(&func_name)?,
+                            type_args,
+                            args,
+                            &mut gas_status,
+                        )
+                        .map(|_| vec![])
+                } else {
+                    // Public functions may return values.
+                    session.execute_function(
+                        &module_id,
+                        IdentStr::new(&func_name)?,
+                        type_args,
+                        args,
+                        &mut gas_status,
+                    )
+                }
+            }
+            None => {
+                let mut script = Vec::new();
+                bi.serialize(&mut script)?;
+                session
+                    .execute_script(script, type_args, args, &mut gas_status)
+                    .map(|_| vec![])
+            }
+        };
+
+        let mut return_values = vec![];
+        let effects = match res {
+            Ok(values) => {
+                return_values = values;
+                let (change_set, events) =
+                    session.finish().map_err(|err| err.into_vm_status())?;
+                Ok(Effects { change_set, events })
+            }
+            Err(err) => Err(err),
+        };
+
+        Ok(ExecutionResult {
+            effects,
+            gas_used: gas_budget.saturating_sub(gas_status.remaining_gas().get()),
+            return_values,
+        })
+    }
+
+    /// Returns a human-readable description of the execution error.
+    pub fn explain_error(&self, err: &VMError) -> String {
+        match (err.major_status(), err.sub_status(), err.location()) {
+            (StatusCode::ABORTED, Some(code), Location::Module(id)) => {
+                match self.ctx.error_descriptions.get_explanation(id, code) {
+                    Some(explanation) => format!(
+                        "Execution aborted with code {} in module {}.\n\
+                        Abort code details:\n\
+                        Reason:\n  Name: {}\n  Description: {}\n\
+                        Category:\n  Name: {}\n  Description: {}",
+                        code,
+                        id,
+                        explanation.reason.code_name,
+                        explanation.reason.code_description,
+                        explanation.category.code_name,
+                        explanation.category.code_description,
+                    ),
+                    None => format!("Execution aborted with code {} in module {}.", code, id),
+                }
+            }
+            (StatusCode::ABORTED, Some(code), _) => {
+                format!(
+                    "Execution aborted with code {} in transaction script.",
+                    code
+                )
+            }
+            (status, _, location) => {
+                format!(
+                    "Execution failed with status {:?} at {:?}.",
+                    status, location
+                )
+            }
+        }
+    }
+}
+
+/// Build config of the local executor.
+pub fn build_config(ctx: &Context) -> BuildConfig {
+    let named_addresses = ctx
+        .address_declarations()
+        .into_iter()
+        .filter_map(|(k, v)| v.map(|v| (k, v)))
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+
+    BuildConfig {
+        dev_mode: true,
+        test_mode: true,
+        generate_docs: false,
+        generate_abis: false,
+        install_dir: None,
+        force_recompilation: false,
+        additional_named_addresses: named_addresses,
+        language_flavor: None,
+    }
+}
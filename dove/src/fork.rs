@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use anyhow::{Error, Result};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::{ModuleId, StructTag};
+use move_core_types::resolver::{ModuleResolver, ResourceResolver};
+use lang::bytecode::accessor::BytecodeType;
+use lang::bytecode::{find, SearchParams};
+use net::{Block, Net, NetView};
+
+use crate::call::bytecode::DoveBytecode;
+use crate::context::Context;
+
+/// Chain state at a pinned block with the project modules on top.
+/// The executed transactions are never committed, so nothing is written to the chain.
+pub struct ForkState {
+    view: NetView,
+    /// Modules of the project package. They shadow the chain modules.
+    project: HashMap<ModuleId, Vec<u8>>,
+    /// Modules of the package dependencies. Used only if the chain has no such module.
+    dependencies: HashMap<ModuleId, Vec<u8>>,
+}
+
+impl ForkState {
+    /// Creates the state over the node at the given block.
+    /// `None` reads the latest state of the backends that can't pin a block.
+    pub fn new(ctx: &Context, net: Box<dyn Net>, block: Option<Block>) -> Result<ForkState> {
+        let project = load_modules(ctx, Some(ctx.manifest.package.name.as_str()))?;
+        let mut dependencies = load_modules(ctx, None)?;
+        dependencies.retain(|id, _| !project.contains_key(id));
+        Ok(ForkState {
+            view: NetView::new(net, block),
+            project,
+            dependencies,
+        })
+    }
+}
+
+/// Loads the built modules of the package or of all packages.
+pub(crate) fn load_modules(
+    ctx: &Context,
+    package: Option<&str>,
+) -> Result<HashMap<ModuleId, Vec<u8>>> {
+    find(
+        DoveBytecode::new(ctx),
+        SearchParams {
+            tp: Some(BytecodeType::Module),
+            package,
+            name: None,
+        },
+    )?
+    .map(|info| {
+        let info = info?;
+        let address = info
+            .address()
+            .ok_or_else(|| anyhow!("Module address is expected"))?;
+        let mut bytes = vec![];
+        info.serialize(&mut bytes)?;
+        Ok((ModuleId::new(address, Identifier::new(info.name())?), bytes))
+    })
+    .collect()
+}
+
+impl ModuleResolver for ForkState {
+    type Error = Error;
+
+    fn get_module(&self, module_id: &ModuleId) -> Result<Option<Vec<u8>>> {
+        if let Some(bytes) = self.project.get(module_id) {
+            return Ok(Some(bytes.clone()));
+        }
+        match self.view.get_module(module_id)? {
+            Some(bytes) => Ok(Some(bytes)),
+            None => Ok(self.dependencies.get(module_id).cloned()),
+        }
+    }
+}
+
+impl ResourceResolver for ForkState {
+    type Error = Error;
+
+    fn get_resource(&self, address: &AccountAddress, tag: &StructTag) -> Result<Option<Vec<u8>>> {
+        self.view.get_resource(address, tag)
+    }
+}
@@ -0,0 +1,519 @@
+use std::fmt::{Display, Formatter};
+use std::panic::{self, AssertUnwindSafe};
+
+use anyhow::Result;
+use rand::rngs::StdRng;
+use rand::Rng;
+use move_binary_format::errors::VMError;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::vm_status::{StatusCode, StatusType};
+use lang::bytecode::info::Type;
+
+use crate::call::bytecode::StructResolver;
+use crate::call::cmd::CallDeclarationCmd;
+use crate::call::fn_call::{is_u256, Config};
+use crate::call::make_transaction;
+use crate::context::Context;
+use crate::executor::Executor;
+use crate::natives::u256::U256;
+
+/// Maximum length of the generated vectors.
+const MAX_VECTOR_LEN: usize = 8;
+/// Maximum nesting of the generated vectors and structs.
+const MAX_DEPTH: usize = 4;
+/// Maximum number of executions spent on minimizing one failure.
+const MAX_SHRINK_RUNS: usize = 200;
+
+/// Function argument in the call syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Arg {
+    Bool(bool),
+    U8(u8),
+    U64(u64),
+    U128(u128),
+    /// `0x1::U256::U256` passed as a decimal number.
+    U256(U256),
+    Address(AccountAddress),
+    Vector(Vec<Arg>),
+    Struct(Vec<Arg>),
+}
+
+impl Display for Arg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let join = |items: &[Arg]| {
+            items
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Arg::Bool(val) => write!(f, "{}", val),
+            Arg::U8(val) => write!(f, "{}", val),
+            Arg::U64(val) => write!(f, "{}", val),
+            Arg::U128(val) => write!(f, "{}", val),
+            Arg::U256(val) => write!(f, "{}", val),
+            Arg::Address(addr) => write!(f, "{}", addr.to_hex_literal()),
+            Arg::Vector(items) => write!(f, "[{}]", join(items)),
+            Arg::Struct(fields) => write!(f, "{{{}}}", join(fields)),
+        }
+    }
+}
+
+impl Arg {
+    /// Returns simpler values of the same type, the simplest first.
+    pub fn shrink(&self) -> Vec<Arg> {
+        match self {
+            Arg::Bool(true) => vec![Arg::Bool(false)],
+            Arg::Bool(false) => vec![],
+            Arg::U8(val) => shrink_int(*val as u128)
+                .map(|val| Arg::U8(val as u8))
+                .collect(),
+            Arg::U64(val) => shrink_int(*val as u128)
+                .map(|val| Arg::U64(val as u64))
+                .collect(),
+            Arg::U128(val) => shrink_int(*val).map(Arg::U128).collect(),
+            Arg::U256(val) => {
+                let mut candidates = vec![
+                    U256::zero(),
+                    U256::one(),
+                    *val / 2,
+                    val.saturating_sub(U256::one()),
+                ];
+                candidates.sort_unstable();
+                candidates.dedup();
+                candidates
+                    .into_iter()
+                    .filter(|candidate| candidate < val)
+                    .map(Arg::U256)
+                    .collect()
+            }
+            Arg::Address(_) => vec![],
+            Arg::Vector(items) => {
+                let mut candidates = vec![];
+                if !items.is_empty() {
+                    candidates.push(Arg::Vector(vec![]));
+                    candidates.push(Arg::Vector(items[..items.len() / 2].to_vec()));
+                    candidates.push(Arg::Vector(items[..items.len() - 1].to_vec()));
+                }
+                candidates.extend(shrink_items(items).into_iter().map(Arg::Vector));
+                candidates.dedup();
+                candidates.retain(|candidate| candidate != self);
+                candidates
+            }
+            Arg::Struct(fields) => shrink_items(fields).into_iter().map(Arg::Struct).collect(),
+        }
+    }
+}
+
+fn shrink_int(val: u128) -> impl Iterator<Item = u128> {
+    let mut candidates = vec![0, 1, val / 2, val.saturating_sub(1)];
+    candidates.sort_unstable();
+    candidates.dedup();
+    candidates
+        .into_iter()
+        .filter(move |candidate| *candidate < val)
+}
+
+/// Shrinks one item at a time.
+fn shrink_items(items: &[Arg]) -> Vec<Vec<Arg>> {
+    items
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, item)| {
+            let simpler = item.shrink().into_iter().next()?;
+            let mut items = items.to_vec();
+            items[idx] = simpler;
+            Some(items)
+        })
+        .collect()
+}
+
+/// Generation mode of the arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Zero values and empty vectors.
+    Min,
+    /// Maximum values and long vectors.
+    Max,
+    /// Random values mixed with the edge cases.
+    Random,
+}
+
+/// Generates function arguments by the parameter types.
+pub struct ArgGenerator<'a> {
+    rng: StdRng,
+    /// Named addresses of the project and the core address.
+    addresses: Vec<AccountAddress>,
+    structs: &'a dyn StructResolver,
+}
+
+impl<'a> ArgGenerator<'a> {
+    /// Creates a new generator.
+    pub fn new(
+        rng: StdRng,
+        addresses: Vec<AccountAddress>,
+        structs: &'a dyn StructResolver,
+    ) -> ArgGenerator<'a> {
+        ArgGenerator {
+            rng,
+            addresses,
+            structs,
+        }
+    }
+
+    /// Generates the value of the given type.
+    pub fn generate(&mut self, tp: &Type, mode: Mode) -> Result<Arg> {
+        self.generate_value(tp, mode, 0)
+    }
+
+    fn generate_value(&mut self, tp: &Type, mode: Mode, depth: usize) -> Result<Arg> {
+        let edge = mode == Mode::Random && self.rng.gen_ratio(1, 4);
+        Ok(match tp {
+            Type::Bool => match mode {
+                Mode::Min => Arg::Bool(false),
+                Mode::Max => Arg::Bool(true),
+                Mode::Random => Arg::Bool(self.rng.gen()),
+            },
+            Type::U8 => Arg::U8(self.int(mode, edge, u8::MAX as u128) as u8),
+            Type::U64 => Arg::U64(self.int(mode, edge, u64::MAX as u128) as u64),
+            Type::U128 => Arg::U128(self.int(mode, edge, u128::MAX)),
+            Type::Address | Type::Signer => Arg::Address(self.address(mode)),
+            Type::Struct(def) if is_u256(def) => Arg::U256(self.u256(mode, edge)),
+            Type::Vector(item) => {
+                let len = match mode {
+                    Mode::Min => 0,
+                    Mode::Max if depth < MAX_DEPTH => MAX_VECTOR_LEN,
+                    Mode::Random if depth < MAX_DEPTH && !edge => {
+                        self.rng.gen_range(0, MAX_VECTOR_LEN + 1)
+                    }
+                    _ => 0,
+                };
+                Arg::Vector(
+                    (0..len)
+                        .map(|_| self.generate_value(item, mode, depth + 1))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            Type::Struct(def) => {
+                ensure!(depth < MAX_DEPTH, "Struct {} is nested too deep", tp);
+                Arg::Struct(
+                    self.structs
+                        .struct_fields(def)?
+                        .iter()
+                        .map(|field| self.generate_value(&field.tp, mode, depth + 1))
+                        .collect::<Result<_>>()?,
+                )
+            }
+            Type::Reference(_) | Type::MutableReference(_) | Type::TypeParameter(_) => {
+                bail!("Parameters of type {} can't be generated", tp)
+            }
+        })
+    }
+
+    fn address(&mut self, mode: Mode) -> AccountAddress {
+        if mode != Mode::Random {
+            return self
+                .addresses
+                .first()
+                .copied()
+                .unwrap_or(AccountAddress::ONE);
+        }
+        let known = self.rng.gen_range(0, self.addresses.len() + 1);
+        match self.addresses.get(known) {
+            Some(addr) => *addr,
+            None => {
+                let mut bytes = [0; AccountAddress::LENGTH];
+                self.rng.fill(&mut bytes[..]);
+                AccountAddress::new(bytes)
+            }
+        }
+    }
+
+    fn u256(&mut self, mode: Mode, edge: bool) -> U256 {
+        let max = U256::max_value();
+        match mode {
+            Mode::Min => U256::zero(),
+            Mode::Max => max,
+            Mode::Random if edge => {
+                [U256::zero(), U256::one(), max - 1, max][self.rng.gen_range(0, 4)]
+            }
+            Mode::Random => {
+                let mut bytes = [0; 32];
+                self.rng.fill(&mut bytes[..]);
+                // Random width, so small values are as likely as large ones.
+                let bits: usize = self.rng.gen_range(0, 257);
+                let val = U256::from_little_endian(&bytes);
+                if bits == 256 {
+                    val
+                } else {
+                    val & ((U256::one() << bits) - 1)
+                }
+            }
+        }
+    }
+
+    fn int(&mut self, mode: Mode, edge: bool, max: u128) -> u128 {
+        match mode {
+            Mode::Min => 0,
+            Mode::Max => max,
+            Mode::Random if edge => [0, 1, max - 1, max][self.rng.gen_range(0, 4)],
+            Mode::Random => self.rng.gen_range(0, max) + self.rng.gen_range(0, 2),
+        }
+    }
+}
+
+/// Unexpected outcome of the execution.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Failure {
+    /// Abort with a code that is not allowed.
+    Abort { code: u64, location: String },
+    /// Execution error other than abort, e.g. an arithmetic error.
+    ExecutionError {
+        status: StatusCode,
+        location: String,
+    },
+    /// VM invariant violation.
+    InvariantViolation {
+        status: StatusCode,
+        location: String,
+    },
+    /// Executor error or panic.
+    Crash(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Abort { code, location } => {
+                write!(f, "unexpected abort with code {} at {}", code, location)
+            }
+            Failure::ExecutionError { status, location } => {
+                write!(f, "execution error {:?} at {}", status, location)
+            }
+            Failure::InvariantViolation { status, location } => {
+                write!(f, "invariant violation {:?} at {}", status, location)
+            }
+            Failure::Crash(err) => write!(f, "crash: {}", err),
+        }
+    }
+}
+
+/// Function under fuzzing.
+pub struct Target<'a> {
+    ctx: &'a Context,
+    executor: Executor<'a>,
+    /// Call without arguments, e.g. `Module::func<u64>`.
+    name: String,
+    package: Option<String>,
+    gas_budget: u64,
+    allowed_aborts: Vec<u64>,
+}
+
+impl<'a> Target<'a> {
+    /// Creates a target executed in the sandbox storage of `executor`.
+    /// Effects of the executions are never committed.
+    pub fn new(
+        ctx: &'a Context,
+        executor: Executor<'a>,
+        name: String,
+        package: Option<String>,
+        gas_budget: u64,
+        allowed_aborts: Vec<u64>,
+    ) -> Target<'a> {
+        Target {
+            ctx,
+            executor,
+            name,
+            package,
+            gas_budget,
+            allowed_aborts,
+        }
+    }
+
+    /// Call with the given arguments in the `dove run` syntax.
+    pub fn call(&self, args: &[Arg]) -> String {
+        let args = args
+            .iter()
+            .map(|arg| arg.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}({})", self.name, args)
+    }
+
+    /// Executes the call. Returns the failure if the outcome is unexpected.
+    /// Arguments rejected by the call parser are a bug of the generator and reported as an error.
+    pub fn run(&self, args: &[Arg]) -> Result<Option<Failure>> {
+        let call = self.call(args);
+        let tx = make_transaction(
+            self.ctx,
+            CallDeclarationCmd::new(call.clone(), self.package.clone()),
+            Config::for_run(),
+        )
+        .map_err(|err| anyhow!("Generated call {} is rejected: {}", call, err))?;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            self.executor.execute(tx, self.gas_budget)
+        }));
+        Ok(match result {
+            Ok(Ok(result)) => match result.effects {
+                Ok(_) => None,
+                Err(err) => self.classify(&err),
+            },
+            Ok(Err(err)) => Some(Failure::Crash(err.to_string())),
+            Err(panic) => Some(Failure::Crash(
+                panic
+                    .downcast_ref::<String>()
+                    .cloned()
+                    .or_else(|| panic.downcast_ref::<&str>().map(|msg| msg.to_string()))
+                    .unwrap_or_else(|| "panic".to_string()),
+            )),
+        })
+    }
+
+    fn classify(&self, err: &VMError) -> Option<Failure> {
+        let location = format!("{:?}", err.location());
+        match (err.major_status(), err.sub_status()) {
+            (StatusCode::ABORTED, Some(code)) if self.allowed_aborts.contains(&code) => None,
+            (StatusCode::ABORTED, Some(code)) => Some(Failure::Abort { code, location }),
+            // Running out of gas is not a bug of the function.
+            (StatusCode::OUT_OF_GAS, _) => None,
+            (status, _) => Some(match status.status_type() {
+                StatusType::InvariantViolation => {
+                    Failure::InvariantViolation { status, location }
+                }
+                _ => Failure::ExecutionError { status, location },
+            }),
+        }
+    }
+
+    /// Greedily replaces the arguments with simpler ones while the failure is the same.
+    pub fn minimize(&self, mut args: Vec<Arg>, failure: &Failure) -> Result<Vec<Arg>> {
+        let mut runs = 0;
+        loop {
+            let mut changed = false;
+            for idx in 0..args.len() {
+                for candidate in args[idx].shrink() {
+                    if runs >= MAX_SHRINK_RUNS {
+                        return Ok(args);
+                    }
+                    runs += 1;
+
+                    let mut simpler = args.clone();
+                    simpler[idx] = candidate;
+                    if self.run(&simpler)?.as_ref() == Some(failure) {
+                        args = simpler;
+                        changed = true;
+                        break;
+                    }
+                }
+            }
+            if !changed {
+                return Ok(args);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Error;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use move_core_types::account_address::AccountAddress;
+    use lang::bytecode::info::{Field, StructDef, Type};
+
+    use super::{Arg, ArgGenerator, Mode};
+    use crate::call::bytecode::StructResolver;
+    use crate::call::fn_call::parse_u256;
+    use crate::natives::u256::U256;
+
+    struct Structs;
+
+    impl StructResolver for Structs {
+        fn struct_fields(&self, _def: &StructDef) -> Result<Vec<Field>, Error> {
+            Ok(vec![Field {
+                name: "a".to_string(),
+                tp: Type::U8,
+            }])
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        let mut gen = ArgGenerator::new(
+            StdRng::seed_from_u64(1),
+            vec![AccountAddress::ONE],
+            &Structs,
+        );
+        let tp = Type::Vector(Box::new(Type::U64));
+        assert_eq!(gen.generate(&tp, Mode::Min).unwrap(), Arg::Vector(vec![]));
+        match gen.generate(&tp, Mode::Max).unwrap() {
+            Arg::Vector(items) => assert!(items.iter().all(|item| *item == Arg::U64(u64::MAX))),
+            arg => panic!("Unexpected {}", arg),
+        }
+
+        let st = Type::Struct(StructDef {
+            address: AccountAddress::ONE,
+            module_name: "M".to_string(),
+            name: "S".to_string(),
+            type_parameters: vec![],
+        });
+        assert_eq!(gen.generate(&st, Mode::Max).unwrap().to_string(), "{255}");
+        assert_eq!(
+            gen.generate(&Type::Signer, Mode::Min).unwrap().to_string(),
+            "0x1"
+        );
+        gen.generate(&Type::TypeParameter(0), Mode::Random)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn test_generate_u256() {
+        let mut gen = ArgGenerator::new(
+            StdRng::seed_from_u64(1),
+            vec![AccountAddress::ONE],
+            &Structs,
+        );
+        let u256 = Type::Struct(StructDef {
+            address: AccountAddress::ONE,
+            module_name: "U256".to_string(),
+            name: "U256".to_string(),
+            type_parameters: vec![],
+        });
+        assert_eq!(gen.generate(&u256, Mode::Min).unwrap().to_string(), "0");
+        assert_eq!(
+            gen.generate(&u256, Mode::Max).unwrap().to_string(),
+            "115792089237316195423570985008687907853269984665640564039457584007913129639935"
+        );
+        for _ in 0..100 {
+            let arg = gen.generate(&u256, Mode::Random).unwrap();
+            assert!(matches!(arg, Arg::U256(_)));
+            parse_u256(&arg.to_string()).unwrap();
+        }
+
+        assert_eq!(
+            Arg::U256(U256::from(10u64)).shrink(),
+            vec![
+                Arg::U256(U256::zero()),
+                Arg::U256(U256::one()),
+                Arg::U256(U256::from(5u64)),
+                Arg::U256(U256::from(9u64))
+            ]
+        );
+        assert!(Arg::U256(U256::zero()).shrink().is_empty());
+    }
+
+    #[test]
+    fn test_shrink() {
+        assert_eq!(
+            Arg::U64(10).shrink(),
+            vec![Arg::U64(0), Arg::U64(1), Arg::U64(5), Arg::U64(9)]
+        );
+        assert!(Arg::U8(0).shrink().is_empty());
+        let vector = Arg::Vector(vec![Arg::Bool(true), Arg::Bool(false)]);
+        assert_eq!(vector.shrink()[0], Arg::Vector(vec![]));
+        assert!(vector
+            .shrink()
+            .contains(&Arg::Vector(vec![Arg::Bool(false), Arg::Bool(false)])));
+    }
+}
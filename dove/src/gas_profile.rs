@@ -0,0 +1,366 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use move_binary_format::file_format_common::instruction_key;
+use move_core_types::gas_schedule::{CostTable, GasCost};
+use move_vm_types::gas_schedule::bytecode_instruction_costs;
+
+use crate::gas_schedule::instruction_name;
+use crate::natives::PontNativeCostIndex;
+use crate::vm_trace::{CallResolver, TraceEntry};
+
+/// Root frame of the folded stacks. Marks the figures as estimates in flamegraphs.
+const FOLDED_ROOT: &str = "estimated gas";
+
+/// Gas profile of the local execution built from the VM instruction trace.
+///
+/// The VM reports only the total. Instructions and natives are re-priced by the cost table
+/// with the minimal abstract memory size, so the per-function, per-instruction and per-native
+/// figures are estimates and size-dependent costs are lower bounds. They don't add up to
+/// `gas_used`, the amount charged by the VM.
+pub struct GasProfile {
+    /// Gas charged by the VM in gas units.
+    pub gas_used: u64,
+    /// Internal gas units per gas unit.
+    pub scaling_factor: u64,
+    /// Estimated costs per function frame.
+    pub frames: Vec<FrameCost>,
+    /// Estimated costs per instruction kind.
+    pub instructions: Vec<InstructionCost>,
+    /// Estimated costs per native function.
+    pub natives: Vec<NativeCost>,
+    folded: BTreeMap<String, u64>,
+}
+
+/// Cost of the function.
+pub struct FrameCost {
+    /// Function name.
+    pub function: String,
+    /// Number of calls.
+    pub calls: u64,
+    /// Estimated gas of the function instructions.
+    pub self_gas: u64,
+    /// Estimated gas of the function and its callees.
+    pub total_gas: u64,
+}
+
+/// Cost of the instruction kind.
+pub struct InstructionCost {
+    /// Instruction name, e.g. `LdU64`.
+    pub instruction: String,
+    /// Number of executions.
+    pub count: u64,
+    /// Estimated gas of all executions.
+    pub gas: u64,
+}
+
+/// Cost of the native function.
+pub struct NativeCost {
+    /// Native function name.
+    pub function: String,
+    /// Cost table index. `None` if the native is not priced by the pontem cost table.
+    pub index: Option<PontNativeCostIndex>,
+    /// Number of calls.
+    pub calls: u64,
+    /// Estimated gas of all calls.
+    pub gas: u64,
+}
+
+impl GasProfile {
+    /// Builds the profile of the traced execution.
+    pub fn new(
+        trace: &[TraceEntry],
+        resolver: &mut CallResolver,
+        cost_table: &CostTable,
+        gas_used: u64,
+    ) -> GasProfile {
+        let instruction_costs = instruction_costs(cost_table);
+
+        let mut stack: Vec<String> = vec![];
+        let mut calls: HashMap<String, u64> = HashMap::new();
+        let mut self_gas: HashMap<String, u64> = HashMap::new();
+        let mut instructions: BTreeMap<String, (u64, u64)> = BTreeMap::new();
+        let mut natives: BTreeMap<String, (Option<PontNativeCostIndex>, u64, u64)> =
+            BTreeMap::new();
+        let mut folded: BTreeMap<String, u64> = BTreeMap::new();
+
+        for (idx, entry) in trace.iter().enumerate() {
+            let function = function_name(entry);
+            let entered = idx
+                .checked_sub(1)
+                .map(|prev| trace[prev].call().is_some() && entry.pc == 0)
+                .unwrap_or(true);
+            if entered {
+                stack.push(function.clone());
+                *calls.entry(function.clone()).or_default() += 1;
+            } else {
+                // Returned to the caller.
+                while stack.len() > 1 && stack.last() != Some(&function) {
+                    stack.pop();
+                }
+            }
+
+            let name = entry.instruction_name();
+            let gas = instruction_costs.get(name).copied().unwrap_or_default();
+            let stat = instructions.entry(name.to_string()).or_default();
+            stat.0 += 1;
+            stat.1 += gas;
+            *self_gas.entry(function).or_default() += gas;
+            *folded.entry(stack.join(";")).or_default() += gas;
+
+            // A call which does not open a new frame is a native call.
+            let next = trace.get(idx + 1);
+            if entry.call().is_none() || next.map(|next| next.pc == 0).unwrap_or(false) {
+                continue;
+            }
+            let native = match resolver.callee(entry) {
+                Some((module, name)) => (
+                    format!("{}::{}", module.short_str_lossless(), name),
+                    PontNativeCostIndex::of(module.name().as_str(), name.as_str()),
+                ),
+                None => ("<unknown native>".to_string(), None),
+            };
+            let gas = native
+                .1
+                .and_then(|index| cost_table.native_table.get(index as usize))
+                .map(total)
+                .unwrap_or_default();
+            let stat = natives.entry(native.0.clone()).or_insert((native.1, 0, 0));
+            stat.1 += 1;
+            stat.2 += gas;
+            *folded
+                .entry(format!("{};{} [native]", stack.join(";"), native.0))
+                .or_default() += gas;
+        }
+
+        let mut frames = calls
+            .into_iter()
+            .map(|(function, calls)| FrameCost {
+                self_gas: self_gas.get(&function).copied().unwrap_or_default(),
+                total_gas: inclusive_gas(&folded, &function),
+                function,
+                calls,
+            })
+            .collect::<Vec<_>>();
+        frames.sort_by(|a, b| {
+            b.total_gas
+                .cmp(&a.total_gas)
+                .then_with(|| a.function.cmp(&b.function))
+        });
+
+        let mut instructions = instructions
+            .into_iter()
+            .map(|(instruction, (count, gas))| InstructionCost {
+                instruction,
+                count,
+                gas,
+            })
+            .collect::<Vec<_>>();
+        instructions.sort_by(|a, b| b.gas.cmp(&a.gas).then_with(|| b.count.cmp(&a.count)));
+
+        let mut natives = natives
+            .into_iter()
+            .map(|(function, (index, calls, gas))| NativeCost {
+                function,
+                index,
+                calls,
+                gas,
+            })
+            .collect::<Vec<_>>();
+        natives.sort_by(|a, b| b.gas.cmp(&a.gas));
+
+        GasProfile {
+            gas_used,
+            scaling_factor: cost_table.gas_constants.gas_unit_scaling_factor,
+            frames,
+            instructions,
+            natives,
+            folded,
+        }
+    }
+
+    /// Estimated gas of the execution in internal gas units.
+    pub fn estimated_gas(&self) -> u64 {
+        self.folded.values().sum()
+    }
+
+    /// Writes the estimated profile in the folded stacks format:
+    /// `estimated gas;main;0x1::M::f 120`.
+    /// The file can be rendered by `flamegraph.pl` or `inferno-flamegraph`.
+    pub fn write_folded(&self, path: &Path) -> Result<()> {
+        let content = self
+            .folded
+            .iter()
+            .filter(|(_, gas)| **gas > 0)
+            .map(|(stack, gas)| format!("{};{} {}\n", FOLDED_ROOT, stack, gas))
+            .collect::<String>();
+        fs::write(path, content)
+            .map_err(|err| anyhow!("Failed to write folded stacks to {:?}: {}", path, err))
+    }
+}
+
+impl Display for GasProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Gas used: {} (charged by the VM)", self.gas_used)?;
+        writeln!(
+            f,
+            "Estimated: {} internal units, {} internal units per gas unit. \
+            The figures below are priced by the cost table with the minimal memory size \
+            and don't add up to the gas used.",
+            self.estimated_gas(),
+            self.scaling_factor
+        )?;
+
+        writeln!(f, "Functions (estimated):")?;
+        writeln!(
+            f,
+            "    {:>10} {:>10} {:>8}  function",
+            "total", "self", "calls"
+        )?;
+        for frame in &self.frames {
+            writeln!(
+                f,
+                "    {:>10} {:>10} {:>8}  {}",
+                frame.total_gas, frame.self_gas, frame.calls, frame.function
+            )?;
+        }
+
+        writeln!(f, "Instructions (estimated):")?;
+        writeln!(f, "    {:>10} {:>8}  instruction", "gas", "count")?;
+        for instr in &self.instructions {
+            writeln!(
+                f,
+                "    {:>10} {:>8}  {}",
+                instr.gas, instr.count, instr.instruction
+            )?;
+        }
+
+        write!(f, "Natives (estimated):")?;
+        if self.natives.is_empty() {
+            write!(f, " none")?;
+        } else {
+            write!(f, "\n    {:>10} {:>8}  function", "gas", "calls")?;
+        }
+        for native in &self.natives {
+            let index = native
+                .index
+                .map(|index| format!("{:?}", index))
+                .unwrap_or_else(|| "not priced".to_string());
+            write!(
+                f,
+                "\n    {:>10} {:>8}  {} ({})",
+                native.gas, native.calls, native.function, index
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Instruction costs keyed by the instruction name.
+fn instruction_costs(cost_table: &CostTable) -> HashMap<String, u64> {
+    let mut instrs = bytecode_instruction_costs();
+    // The cost table is sorted by instruction order.
+    instrs.sort_by_key(|cost| instruction_key(&cost.0));
+    instrs
+        .iter()
+        .zip(&cost_table.instruction_table)
+        .map(|((instr, _), cost)| (instruction_name(instr), total(cost)))
+        .collect()
+}
+
+fn total(cost: &GasCost) -> u64 {
+    cost.instruction_gas.get() + cost.memory_gas.get()
+}
+
+fn function_name(entry: &TraceEntry) -> String {
+    match entry.module_id() {
+        Some(module) => format!("{}::{}", module.short_str_lossless(), entry.function_name()),
+        None => entry.function.clone(),
+    }
+}
+
+/// Gas of the stacks containing the function. Recursive calls are counted once.
+fn inclusive_gas(folded: &BTreeMap<String, u64>, function: &str) -> u64 {
+    folded
+        .iter()
+        .filter(|(stack, _)| stack.split(';').collect::<HashSet<_>>().contains(function))
+        .map(|(_, gas)| gas)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use move_core_types::language_storage::ModuleId;
+    use move_core_types::resolver::ModuleResolver;
+
+    use super::GasProfile;
+    use crate::natives::pontem_cost_table;
+    use crate::vm_trace::{CallResolver, TraceEntry};
+
+    struct NoModules;
+
+    impl ModuleResolver for NoModules {
+        type Error = anyhow::Error;
+
+        fn get_module(&self, _id: &ModuleId) -> Result<Option<Vec<u8>>, Self::Error> {
+            Ok(None)
+        }
+    }
+
+    fn entry(function: &str, pc: u16, instruction: &str) -> TraceEntry {
+        TraceEntry {
+            function: function.to_string(),
+            pc,
+            instruction: instruction.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_profile() {
+        let store = "0x00000000000000000000000000000001::Store::get";
+        let trace = vec![
+            entry("Script::main", 0, "LdU64(1)"),
+            entry("Script::main", 1, "Call(FunctionHandleIndex(0))"),
+            entry(store, 0, "LdU64(2)"),
+            entry(store, 1, "Call(FunctionHandleIndex(1))"),
+            entry(store, 2, "Ret"),
+            entry("Script::main", 2, "Pop"),
+            entry("Script::main", 3, "Ret"),
+        ];
+        let cost_table = pontem_cost_table();
+        let modules = NoModules;
+        let mut resolver = CallResolver::new(&modules, None);
+        let profile = GasProfile::new(&trace, &mut resolver, &cost_table, 1);
+
+        assert_eq!(profile.frames.len(), 2);
+        assert_eq!(profile.frames[0].function, "Script::main");
+        assert_eq!(profile.frames[0].total_gas, profile.estimated_gas());
+        assert_eq!(profile.frames[1].function, "0x1::Store::get");
+        assert_eq!(profile.frames[1].calls, 1);
+        assert!(profile.frames[1].self_gas < profile.frames[0].total_gas);
+
+        let ld = profile
+            .instructions
+            .iter()
+            .find(|instr| instr.instruction == "LdU64")
+            .unwrap();
+        assert_eq!(ld.count, 2);
+        assert_eq!(profile.natives.len(), 1);
+        assert_eq!(profile.natives[0].calls, 1);
+        assert!(profile
+            .folded
+            .keys()
+            .any(|stack| stack == "Script::main;0x1::Store::get"));
+
+        let path = std::env::temp_dir().join("dove_gas_profile_test.folded");
+        profile.write_folded(&path).unwrap();
+        let folded = std::fs::read_to_string(&path).unwrap();
+        assert!(folded
+            .lines()
+            .all(|line| line.starts_with("estimated gas;Script::main")));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Error, Result};
+use serde::{Deserialize, Serialize};
+use move_binary_format::file_format::Bytecode;
+use move_binary_format::file_format_common::instruction_key;
+use move_core_types::gas_schedule::{CostTable, GasCost};
+use move_vm_types::gas_schedule::{bytecode_instruction_costs, new_from_instructions};
+
+use crate::natives::PontNativeCostIndex;
+
+/// Cost table with named entries.
+/// Instructions are named as `Bytecode` variants, natives as `PontNativeCostIndex` variants.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct GasSchedule {
+    #[serde(default)]
+    pub instructions: BTreeMap<String, Cost>,
+    #[serde(default)]
+    pub natives: BTreeMap<String, Cost>,
+}
+
+/// Cost of an instruction or a native function.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Cost {
+    pub instruction_gas: u64,
+    pub memory_gas: u64,
+}
+
+impl From<&GasCost> for Cost {
+    fn from(cost: &GasCost) -> Self {
+        Cost {
+            instruction_gas: cost.instruction_gas.get(),
+            memory_gas: cost.memory_gas.get(),
+        }
+    }
+}
+
+impl From<Cost> for GasCost {
+    fn from(cost: Cost) -> Self {
+        GasCost::new(cost.instruction_gas, cost.memory_gas)
+    }
+}
+
+impl GasSchedule {
+    /// Loads the schedule from a TOML or JSON file. The format is selected by the file extension.
+    pub fn load(path: &Path) -> Result<GasSchedule> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read {}: {}", path.display(), err))?;
+        let schedule = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_str(&content).map_err(Error::from),
+            Some("toml") => toml::from_str(&content).map_err(Error::from),
+            _ => bail!(
+                "Unsupported gas schedule format {}. Expected a .toml or .json file",
+                path.display()
+            ),
+        };
+        schedule.map_err(|err| anyhow!("Failed to parse {}: {}", path.display(), err))
+    }
+
+    /// Names the entries of the cost table.
+    pub fn from_cost_table(cost_table: &CostTable) -> GasSchedule {
+        let instructions = sorted_instructions()
+            .iter()
+            .zip(&cost_table.instruction_table)
+            .map(|(instr, cost)| (instruction_name(instr), Cost::from(cost)))
+            .collect();
+        let natives = PontNativeCostIndex::ALL
+            .iter()
+            .filter_map(|index| {
+                cost_table
+                    .native_table
+                    .get(*index as usize)
+                    .map(|cost| (format!("{:?}", index), Cost::from(cost)))
+            })
+            .collect();
+        GasSchedule {
+            instructions,
+            natives,
+        }
+    }
+
+    /// Builds the cost table. Every instruction and native must have exactly one entry.
+    pub fn to_cost_table(&self) -> Result<CostTable> {
+        let instructions = sorted_instructions();
+        let instruction_names = instructions
+            .iter()
+            .map(instruction_name)
+            .collect::<Vec<_>>();
+        let native_names = PontNativeCostIndex::ALL
+            .iter()
+            .map(|index| format!("{:?}", index))
+            .collect::<Vec<_>>();
+
+        let mut errors = vec![];
+        check_entries(
+            "instructions",
+            &instruction_names,
+            &self.instructions,
+            &mut errors,
+        );
+        check_entries("natives", &native_names, &self.natives, &mut errors);
+        ensure!(errors.is_empty(), "{}", errors.join("\n"));
+
+        let instrs = instructions
+            .into_iter()
+            .zip(&instruction_names)
+            .map(|(instr, name)| (instr, self.instructions[name].into()))
+            .collect();
+        let natives = native_names
+            .iter()
+            .map(|name| self.natives[name].into())
+            .collect();
+        Ok(new_from_instructions(instrs, natives))
+    }
+}
+
+/// Loads and validates the cost table.
+pub fn load_cost_table(path: &Path) -> Result<CostTable> {
+    GasSchedule::load(path)?
+        .to_cost_table()
+        .map_err(|err| anyhow!("Invalid gas schedule {}:\n{}", path.display(), err))
+}
+
+/// Name of the instruction without its operands, e.g. `LdU8`.
+pub fn instruction_name(instr: &Bytecode) -> String {
+    let name = format!("{:?}", instr);
+    name.split('(').next().unwrap_or(&name).to_string()
+}
+
+/// Instructions in the order of the cost table.
+fn sorted_instructions() -> Vec<Bytecode> {
+    let mut instrs = bytecode_instruction_costs();
+    instrs.sort_by_key(|cost| instruction_key(&cost.0));
+    instrs.into_iter().map(|(instr, _)| instr).collect()
+}
+
+fn check_entries(
+    section: &str,
+    expected: &[String],
+    entries: &BTreeMap<String, Cost>,
+    errors: &mut Vec<String>,
+) {
+    let missing = expected
+        .iter()
+        .filter(|name| !entries.contains_key(*name))
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    if !missing.is_empty() {
+        errors.push(format!("missing {}: {}", section, missing.join(", ")));
+    }
+    let unknown = entries
+        .keys()
+        .filter(|name| !expected.contains(*name))
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    if !unknown.is_empty() {
+        errors.push(format!("unknown {}: {}", section, unknown.join(", ")));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::natives::{pontem_cost_table, PontNativeCostIndex};
+    use super::{instruction_name, sorted_instructions, Cost, GasSchedule};
+
+    #[test]
+    fn test_default_schedule() {
+        let names = sorted_instructions()
+            .iter()
+            .map(instruction_name)
+            .collect::<HashSet<_>>();
+        assert_eq!(names.len(), sorted_instructions().len());
+        for (idx, index) in PontNativeCostIndex::ALL.iter().enumerate() {
+            assert_eq!(*index as usize, idx);
+        }
+
+        let default = pontem_cost_table();
+        let schedule = GasSchedule::from_cost_table(&default);
+        assert_eq!(
+            schedule.natives["SHA2_256"],
+            Cost {
+                instruction_gas: 21,
+                memory_gas: 1
+            }
+        );
+        let cost_table = schedule.to_cost_table().unwrap();
+        assert_eq!(cost_table.instruction_table, default.instruction_table);
+        assert_eq!(cost_table.native_table, default.native_table);
+
+        let toml = toml::to_string(&schedule).unwrap();
+        assert_eq!(toml::from_str::<GasSchedule>(&toml).unwrap(), schedule);
+    }
+
+    #[test]
+    fn test_invalid_schedule() {
+        let mut schedule = GasSchedule::from_cost_table(&pontem_cost_table());
+        let cost = schedule.natives.remove("U256_ADD").unwrap();
+        schedule.natives.insert("U256_XOR_ALL".to_string(), cost);
+        schedule.instructions.remove("Pop");
+
+        let err = schedule.to_cost_table().unwrap_err().to_string();
+        assert!(err.contains("missing instructions: Pop"));
+        assert!(err.contains("missing natives: U256_ADD"));
+        assert!(err.contains("unknown natives: U256_XOR_ALL"));
+    }
+}
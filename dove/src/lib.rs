@@ -28,8 +28,30 @@ pub mod cli;
 pub mod cmd;
 /// Dove execution context.
 pub mod context;
+/// Execution error diagnostics.
+pub mod diagnostics;
+/// Local transaction executor.
+pub mod executor;
+/// Local execution against the chain state.
+pub mod fork;
+/// Signature-driven fuzzing.
+pub mod fuzz;
+/// Gas profiling of local execution.
+pub mod gas_profile;
+/// Cost table loaded from a file.
+pub mod gas_schedule;
 /// Native functions.
 pub mod natives;
+/// Execution report.
+pub mod report;
+/// Named sandboxes and snapshots.
+pub mod sandbox;
+/// Multi-step scenarios over the sandbox storage.
+pub mod scenario;
+/// Declarative sandbox state.
+pub mod state;
+/// VM instruction trace.
+pub mod vm_trace;
 /// To work with stored access keys
 pub mod wallet_key;
 
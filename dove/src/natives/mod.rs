@@ -9,7 +9,7 @@ use move_vm_types::gas_schedule::{bytecode_instruction_costs, new_from_instructi
 mod account;
 mod reflect;
 mod signature;
-mod u256;
+pub(crate) mod u256;
 
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -44,6 +44,24 @@ pub enum PontNativeCostIndex {
     U256_SUB = 26,
     U256_ADD = 27,
     MOD_ADDRESS_OF = 28,
+    U256_MOD = 29,
+    U256_SHL = 30,
+    U256_SHR = 31,
+    U256_AND = 32,
+    U256_OR = 33,
+    U256_XOR = 34,
+    U256_COMPARE = 35,
+    U256_POW = 36,
+    U256_CHECKED_ADD = 37,
+    U256_CHECKED_SUB = 38,
+    U256_CHECKED_MUL = 39,
+    U256_CHECKED_DIV = 40,
+    U256_CHECKED_MOD = 41,
+    U256_CHECKED_POW = 42,
+    U256_TO_BYTES_LE = 43,
+    U256_TO_BYTES_BE = 44,
+    U256_FROM_BYTES_LE = 45,
+    U256_FROM_BYTES_BE = 46,
 }
 
 impl From<PontNativeCostIndex> for u8 {
@@ -52,6 +70,69 @@ impl From<PontNativeCostIndex> for u8 {
     }
 }
 
+impl PontNativeCostIndex {
+    /// All cost indexes in the order of the native cost table.
+    pub const ALL: [PontNativeCostIndex; 47] = [
+        PontNativeCostIndex::SHA2_256,
+        PontNativeCostIndex::SHA3_256,
+        PontNativeCostIndex::ED25519_VERIFY,
+        PontNativeCostIndex::ED25519_THRESHOLD_VERIFY,
+        PontNativeCostIndex::BCS_TO_BYTES,
+        PontNativeCostIndex::LENGTH,
+        PontNativeCostIndex::EMPTY,
+        PontNativeCostIndex::BORROW,
+        PontNativeCostIndex::BORROW_MUT,
+        PontNativeCostIndex::PUSH_BACK,
+        PontNativeCostIndex::POP_BACK,
+        PontNativeCostIndex::DESTROY_EMPTY,
+        PontNativeCostIndex::SWAP,
+        PontNativeCostIndex::ED25519_VALIDATE_KEY,
+        PontNativeCostIndex::SIGNER_BORROW,
+        PontNativeCostIndex::CREATE_SIGNER,
+        PontNativeCostIndex::DESTROY_SIGNER,
+        PontNativeCostIndex::EMIT_EVENT,
+        PontNativeCostIndex::U256_FROM_U8,
+        PontNativeCostIndex::U256_FROM_U64,
+        PontNativeCostIndex::U256_FROM_U128,
+        PontNativeCostIndex::U256_AS_U8,
+        PontNativeCostIndex::U256_AS_U64,
+        PontNativeCostIndex::U256_AS_U128,
+        PontNativeCostIndex::U256_MUL,
+        PontNativeCostIndex::U256_DIV,
+        PontNativeCostIndex::U256_SUB,
+        PontNativeCostIndex::U256_ADD,
+        PontNativeCostIndex::MOD_ADDRESS_OF,
+        PontNativeCostIndex::U256_MOD,
+        PontNativeCostIndex::U256_SHL,
+        PontNativeCostIndex::U256_SHR,
+        PontNativeCostIndex::U256_AND,
+        PontNativeCostIndex::U256_OR,
+        PontNativeCostIndex::U256_XOR,
+        PontNativeCostIndex::U256_COMPARE,
+        PontNativeCostIndex::U256_POW,
+        PontNativeCostIndex::U256_CHECKED_ADD,
+        PontNativeCostIndex::U256_CHECKED_SUB,
+        PontNativeCostIndex::U256_CHECKED_MUL,
+        PontNativeCostIndex::U256_CHECKED_DIV,
+        PontNativeCostIndex::U256_CHECKED_MOD,
+        PontNativeCostIndex::U256_CHECKED_POW,
+        PontNativeCostIndex::U256_TO_BYTES_LE,
+        PontNativeCostIndex::U256_TO_BYTES_BE,
+        PontNativeCostIndex::U256_FROM_BYTES_LE,
+        PontNativeCostIndex::U256_FROM_BYTES_BE,
+    ];
+
+    /// Returns the cost index of the native function.
+    pub fn of(module: &str, function: &str) -> Option<PontNativeCostIndex> {
+        PONT_NATIVES
+            .iter()
+            .map(|(module, function, _, index)| (*module, *function, *index))
+            .chain(STDLIB_NATIVES.iter().copied())
+            .find(|native| native.0 == module && native.1 == function)
+            .map(|(_, _, index)| index)
+    }
+}
+
 pub fn pontem_cost_table() -> CostTable {
     let mut instrs = bytecode_instruction_costs();
     // Note that the DiemVM is expecting the table sorted by instruction order.
@@ -89,6 +170,24 @@ pub fn pontem_cost_table() -> CostTable {
         (N::U256_SUB, GasCost::new(10, 1)),
         (N::U256_ADD, GasCost::new(10, 1)),
         (N::MOD_ADDRESS_OF, GasCost::new(10, 1)),
+        (N::U256_MOD, GasCost::new(10, 1)),
+        (N::U256_SHL, GasCost::new(10, 1)),
+        (N::U256_SHR, GasCost::new(10, 1)),
+        (N::U256_AND, GasCost::new(10, 1)),
+        (N::U256_OR, GasCost::new(10, 1)),
+        (N::U256_XOR, GasCost::new(10, 1)),
+        (N::U256_COMPARE, GasCost::new(10, 1)),
+        (N::U256_POW, GasCost::new(20, 1)),
+        (N::U256_CHECKED_ADD, GasCost::new(10, 1)),
+        (N::U256_CHECKED_SUB, GasCost::new(10, 1)),
+        (N::U256_CHECKED_MUL, GasCost::new(10, 1)),
+        (N::U256_CHECKED_DIV, GasCost::new(10, 1)),
+        (N::U256_CHECKED_MOD, GasCost::new(10, 1)),
+        (N::U256_CHECKED_POW, GasCost::new(20, 1)),
+        (N::U256_TO_BYTES_LE, GasCost::new(10, 1)),
+        (N::U256_TO_BYTES_BE, GasCost::new(10, 1)),
+        (N::U256_FROM_BYTES_LE, GasCost::new(10, 1)),
+        (N::U256_FROM_BYTES_BE, GasCost::new(10, 1)),
     ];
     native_table.sort_by_key(|cost| cost.0 as u64);
     let raw_native_table = native_table
@@ -106,51 +205,195 @@ pub fn all_natives() -> NativeFunctionTable {
 }
 
 pub fn pontem_natives(diem_framework_addr: AccountAddress) -> NativeFunctionTable {
-    const NATIVES: &[(&str, &str, NativeFunction)] = &[
-        ("U256", "from_u8", u256::from_u8),
-        ("U256", "from_u64", u256::from_u64),
-        ("U256", "from_u128", u256::from_u128),
-        ("U256", "as_u8", u256::as_u8),
-        ("U256", "as_u64", u256::as_u64),
-        ("U256", "as_u128", u256::as_u128),
-        ("U256", "add", u256::add),
-        ("U256", "sub", u256::sub),
-        ("U256", "mul", u256::mul),
-        ("U256", "div", u256::div),
-        ("Reflect", "mod_address_of", reflect::mod_address_of),
+    PONT_NATIVES
+        .iter()
+        .map(|(module_name, func_name, func, _)| {
+            (
+                diem_framework_addr,
+                Identifier::new(*module_name).unwrap(),
+                Identifier::new(*func_name).unwrap(),
+                *func,
+            )
+        })
+        .collect()
+}
+
+/// Natives of the pontem framework: module, function, implementation and cost index.
+const PONT_NATIVES: &[(&str, &str, NativeFunction, PontNativeCostIndex)] = {
+    use PontNativeCostIndex as N;
+    &[
+        ("U256", "from_u8", u256::from_u8, N::U256_FROM_U8),
+        ("U256", "from_u64", u256::from_u64, N::U256_FROM_U64),
+        ("U256", "from_u128", u256::from_u128, N::U256_FROM_U128),
+        ("U256", "as_u8", u256::as_u8, N::U256_AS_U8),
+        ("U256", "as_u64", u256::as_u64, N::U256_AS_U64),
+        ("U256", "as_u128", u256::as_u128, N::U256_AS_U128),
+        ("U256", "add", u256::add, N::U256_ADD),
+        ("U256", "sub", u256::sub, N::U256_SUB),
+        ("U256", "mul", u256::mul, N::U256_MUL),
+        ("U256", "div", u256::div, N::U256_DIV),
+        ("U256", "mod", u256::mod_, N::U256_MOD),
+        ("U256", "shl", u256::shl, N::U256_SHL),
+        ("U256", "shr", u256::shr, N::U256_SHR),
+        ("U256", "and", u256::and, N::U256_AND),
+        ("U256", "or", u256::or, N::U256_OR),
+        ("U256", "xor", u256::xor, N::U256_XOR),
+        ("U256", "compare", u256::compare, N::U256_COMPARE),
+        ("U256", "pow", u256::pow, N::U256_POW),
+        (
+            "U256",
+            "checked_add",
+            u256::checked_add,
+            N::U256_CHECKED_ADD,
+        ),
+        (
+            "U256",
+            "checked_sub",
+            u256::checked_sub,
+            N::U256_CHECKED_SUB,
+        ),
+        (
+            "U256",
+            "checked_mul",
+            u256::checked_mul,
+            N::U256_CHECKED_MUL,
+        ),
+        (
+            "U256",
+            "checked_div",
+            u256::checked_div,
+            N::U256_CHECKED_DIV,
+        ),
+        (
+            "U256",
+            "checked_mod",
+            u256::checked_mod,
+            N::U256_CHECKED_MOD,
+        ),
+        (
+            "U256",
+            "checked_pow",
+            u256::checked_pow,
+            N::U256_CHECKED_POW,
+        ),
+        (
+            "U256",
+            "to_bytes_le",
+            u256::to_bytes_le,
+            N::U256_TO_BYTES_LE,
+        ),
+        (
+            "U256",
+            "to_bytes_be",
+            u256::to_bytes_be,
+            N::U256_TO_BYTES_BE,
+        ),
+        (
+            "U256",
+            "from_bytes_le",
+            u256::from_bytes_le,
+            N::U256_FROM_BYTES_LE,
+        ),
+        (
+            "U256",
+            "from_bytes_be",
+            u256::from_bytes_be,
+            N::U256_FROM_BYTES_BE,
+        ),
+        (
+            "Reflect",
+            "mod_address_of",
+            reflect::mod_address_of,
+            N::MOD_ADDRESS_OF,
+        ),
         (
             "PontAccount",
             "create_signer",
             account::native_create_signer,
+            N::CREATE_SIGNER,
+        ),
+        (
+            "Account",
+            "create_signer",
+            account::native_create_signer,
+            N::CREATE_SIGNER,
         ),
-        ("Account", "create_signer", account::native_create_signer),
         (
             "PontAccount",
             "destroy_signer",
             account::native_destroy_signer,
+            N::DESTROY_SIGNER,
+        ),
+        (
+            "Account",
+            "destroy_signer",
+            account::native_destroy_signer,
+            N::DESTROY_SIGNER,
         ),
-        ("Account", "destroy_signer", account::native_destroy_signer),
         (
             "Signature",
             "ed25519_validate_pubkey",
             signature::native_ed25519_publickey_validation,
+            N::ED25519_VALIDATE_KEY,
         ),
         (
             "Signature",
             "ed25519_verify",
             signature::native_ed25519_signature_verification,
+            N::ED25519_VERIFY,
         ),
-    ];
-    NATIVES
-        .iter()
-        .cloned()
-        .map(|(module_name, func_name, func)| {
-            (
-                diem_framework_addr,
-                Identifier::new(module_name).unwrap(),
-                Identifier::new(func_name).unwrap(),
-                func,
+    ]
+};
+
+/// Natives registered by the Move standard library and priced by the pontem cost table.
+const STDLIB_NATIVES: &[(&str, &str, PontNativeCostIndex)] = {
+    use PontNativeCostIndex as N;
+    &[
+        ("Hash", "sha2_256", N::SHA2_256),
+        ("Hash", "sha3_256", N::SHA3_256),
+        ("BCS", "to_bytes", N::BCS_TO_BYTES),
+        ("Vector", "length", N::LENGTH),
+        ("Vector", "empty", N::EMPTY),
+        ("Vector", "borrow", N::BORROW),
+        ("Vector", "borrow_mut", N::BORROW_MUT),
+        ("Vector", "push_back", N::PUSH_BACK),
+        ("Vector", "pop_back", N::POP_BACK),
+        ("Vector", "destroy_empty", N::DESTROY_EMPTY),
+        ("Vector", "swap", N::SWAP),
+        ("Signer", "borrow_address", N::SIGNER_BORROW),
+        ("Event", "write_to_event_store", N::EMIT_EVENT),
+    ]
+};
+
+#[cfg(test)]
+mod tests {
+    use super::{PontNativeCostIndex, PONT_NATIVES, STDLIB_NATIVES};
+
+    #[test]
+    fn test_cost_index_of() {
+        assert_eq!(
+            PontNativeCostIndex::of("U256", "checked_add"),
+            Some(PontNativeCostIndex::U256_CHECKED_ADD)
+        );
+        assert_eq!(
+            PontNativeCostIndex::of("Vector", "length"),
+            Some(PontNativeCostIndex::LENGTH)
+        );
+        assert_eq!(PontNativeCostIndex::of("U256", "sqrt"), None);
+
+        // A native is registered once, by pontem or by the standard library.
+        let mut names = PONT_NATIVES
+            .iter()
+            .map(|(module, function, _, _)| (*module, *function))
+            .chain(
+                STDLIB_NATIVES
+                    .iter()
+                    .map(|(module, function, _)| (*module, *function)),
             )
-        })
-        .collect()
+            .collect::<Vec<_>>();
+        let count = names.len();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), count);
+    }
 }
@@ -1,5 +1,6 @@
 #![allow(clippy::ptr_offset_with_cast, clippy::assign_op_pattern)]
 
+use core::cmp::Ordering;
 use core::ops::Div;
 use move_binary_format::errors::{PartialVMError, PartialVMResult};
 use move_core_types::vm_status::StatusCode;
@@ -10,7 +11,7 @@ use move_vm_types::{
     pop_arg,
     values::{values_impl::Struct, Value},
 };
-use smallvec::smallvec;
+use smallvec::{smallvec, SmallVec};
 use std::{borrow::ToOwned, collections::VecDeque, format, vec, vec::Vec};
 use crate::natives::PontNativeCostIndex;
 
@@ -118,6 +119,177 @@ pub fn as_u128(
 }
 
 pub fn mul(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_MUL, try_mul)
+}
+
+pub fn div(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_DIV, try_div)
+}
+
+pub fn sub(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_SUB, try_sub)
+}
+
+pub fn add(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_ADD, try_add)
+}
+
+pub fn mod_(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_MOD, try_mod)
+}
+
+pub fn and(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_AND, |l, r| {
+        Ok(l & r)
+    })
+}
+
+pub fn or(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_OR, |l, r| {
+        Ok(l | r)
+    })
+}
+
+pub fn xor(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_XOR, |l, r| {
+        Ok(l ^ r)
+    })
+}
+
+pub fn pow(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    binary(context, arguments, PontNativeCostIndex::U256_POW, try_pow)
+}
+
+pub fn checked_add(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    checked(
+        context,
+        arguments,
+        PontNativeCostIndex::U256_CHECKED_ADD,
+        try_add,
+    )
+}
+
+pub fn checked_sub(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    checked(
+        context,
+        arguments,
+        PontNativeCostIndex::U256_CHECKED_SUB,
+        try_sub,
+    )
+}
+
+pub fn checked_mul(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    checked(
+        context,
+        arguments,
+        PontNativeCostIndex::U256_CHECKED_MUL,
+        try_mul,
+    )
+}
+
+pub fn checked_div(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    checked(
+        context,
+        arguments,
+        PontNativeCostIndex::U256_CHECKED_DIV,
+        try_div,
+    )
+}
+
+pub fn checked_mod(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    checked(
+        context,
+        arguments,
+        PontNativeCostIndex::U256_CHECKED_MOD,
+        try_mod,
+    )
+}
+
+pub fn checked_pow(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    checked(
+        context,
+        arguments,
+        PontNativeCostIndex::U256_CHECKED_POW,
+        try_pow,
+    )
+}
+
+pub fn shl(
     context: &mut NativeContext,
     ty_args: Vec<Type>,
     mut arguments: VecDeque<Value>,
@@ -125,20 +297,36 @@ pub fn mul(
     debug_assert!(ty_args.is_empty());
     debug_assert!(arguments.len() == 2);
 
-    let r = unwrap_u256(pop_arg!(arguments, Struct))?;
-    let l = unwrap_u256(pop_arg!(arguments, Struct))?;
+    let shift = pop_arg!(arguments, u8);
+    let u256 = unwrap_u256(pop_arg!(arguments, Struct))?;
 
-    let (res, overflowed) = l.overflowing_mul(r);
-    if overflowed {
-        return Err(PartialVMError::new(StatusCode::ARITHMETIC_ERROR)
-            .with_message(format!("Cannot mul {:?} and {:?}", l, r)));
-    }
+    let cost = native_gas(context.cost_table(), PontNativeCostIndex::U256_SHL, 0);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![wrap_u256(shl_u256(u256, shift))],
+    ))
+}
 
-    let cost = native_gas(context.cost_table(), PontNativeCostIndex::U256_MUL, 0);
-    Ok(NativeResult::ok(cost, smallvec![wrap_u256(res)]))
+pub fn shr(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let shift = pop_arg!(arguments, u8);
+    let u256 = unwrap_u256(pop_arg!(arguments, Struct))?;
+
+    let cost = native_gas(context.cost_table(), PontNativeCostIndex::U256_SHR, 0);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![wrap_u256(shr_u256(u256, shift))],
+    ))
 }
 
-pub fn div(
+/// Returns `EQUAL` (0), `LESS_THAN` (1) or `GREATER_THAN` (2).
+pub fn compare(
     context: &mut NativeContext,
     ty_args: Vec<Type>,
     mut arguments: VecDeque<Value>,
@@ -146,59 +334,232 @@ pub fn div(
     debug_assert!(ty_args.is_empty());
     debug_assert!(arguments.len() == 2);
 
-    let r = unwrap_u256(pop_arg!(arguments, Struct))?;
-    let l = unwrap_u256(pop_arg!(arguments, Struct))?;
+    let (l, r) = pop_operands(&mut arguments)?;
 
-    if r == U256::zero() {
-        return Err(PartialVMError::new(StatusCode::ARITHMETIC_ERROR)
-            .with_message(format!("Cannot div {:?} by {:?}", l, r)));
-    }
+    let cost = native_gas(context.cost_table(), PontNativeCostIndex::U256_COMPARE, 0);
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u8(compare_u256(l, r))],
+    ))
+}
 
-    let res = l.div(r);
-    let cost = native_gas(context.cost_table(), PontNativeCostIndex::U256_DIV, 0);
-    Ok(NativeResult::ok(cost, smallvec![wrap_u256(res)]))
+pub fn to_bytes_le(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let u256 = unwrap_u256(pop_arg!(arguments, Struct))?;
+    let cost = native_gas(
+        context.cost_table(),
+        PontNativeCostIndex::U256_TO_BYTES_LE,
+        0,
+    );
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(to_bytes(u256, false))],
+    ))
 }
 
-pub fn sub(
+pub fn to_bytes_be(
     context: &mut NativeContext,
     ty_args: Vec<Type>,
     mut arguments: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
     debug_assert!(ty_args.is_empty());
-    debug_assert!(arguments.len() == 2);
+    debug_assert!(arguments.len() == 1);
 
-    let r = unwrap_u256(pop_arg!(arguments, Struct))?;
-    let l = unwrap_u256(pop_arg!(arguments, Struct))?;
+    let u256 = unwrap_u256(pop_arg!(arguments, Struct))?;
+    let cost = native_gas(
+        context.cost_table(),
+        PontNativeCostIndex::U256_TO_BYTES_BE,
+        0,
+    );
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(to_bytes(u256, true))],
+    ))
+}
 
-    let (res, overflowed) = l.overflowing_sub(r);
-    if overflowed {
-        return Err(PartialVMError::new(StatusCode::ARITHMETIC_ERROR)
-            .with_message(format!("Cannot sub {:?} from {:?}", r, l)));
-    }
+/// Accepts up to 32 bytes. Shorter vectors are padded with zero high-order bytes.
+pub fn from_bytes_le(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
 
-    let cost = native_gas(context.cost_table(), PontNativeCostIndex::U256_SUB, 0);
-    Ok(NativeResult::ok(cost, smallvec![wrap_u256(res)]))
+    let u256 = from_bytes(&pop_arg!(arguments, Vec<u8>), false)?;
+    let cost = native_gas(
+        context.cost_table(),
+        PontNativeCostIndex::U256_FROM_BYTES_LE,
+        0,
+    );
+    Ok(NativeResult::ok(cost, smallvec![wrap_u256(u256)]))
 }
 
-pub fn add(
+/// Accepts up to 32 bytes. Shorter vectors are padded with zero high-order bytes.
+pub fn from_bytes_be(
     context: &mut NativeContext,
     ty_args: Vec<Type>,
     mut arguments: VecDeque<Value>,
 ) -> PartialVMResult<NativeResult> {
     debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let u256 = from_bytes(&pop_arg!(arguments, Vec<u8>), true)?;
+    let cost = native_gas(
+        context.cost_table(),
+        PontNativeCostIndex::U256_FROM_BYTES_BE,
+        0,
+    );
+    Ok(NativeResult::ok(cost, smallvec![wrap_u256(u256)]))
+}
+
+/// Applies the operation to two `U256` arguments. Operation errors abort the execution.
+fn binary(
+    context: &mut NativeContext,
+    mut arguments: VecDeque<Value>,
+    index: PontNativeCostIndex,
+    op: fn(U256, U256) -> PartialVMResult<U256>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(arguments.len() == 2);
+
+    let (l, r) = pop_operands(&mut arguments)?;
+
+    let res = op(l, r)?;
+    let cost = native_gas(context.cost_table(), index, 0);
+    Ok(NativeResult::ok(cost, smallvec![wrap_u256(res)]))
+}
+
+/// Applies the operation to two `U256` arguments.
+/// Returns `(true, result)` or `(false, 0)` if the operation failed.
+fn checked(
+    context: &mut NativeContext,
+    mut arguments: VecDeque<Value>,
+    index: PontNativeCostIndex,
+    op: fn(U256, U256) -> PartialVMResult<U256>,
+) -> PartialVMResult<NativeResult> {
     debug_assert!(arguments.len() == 2);
 
-    let r = unwrap_u256(pop_arg!(arguments, Struct))?;
-    let l = unwrap_u256(pop_arg!(arguments, Struct))?;
+    let (l, r) = pop_operands(&mut arguments)?;
 
-    let (res, overflowed) = l.overflowing_add(r);
-    if overflowed {
-        return Err(PartialVMError::new(StatusCode::ARITHMETIC_ERROR)
-            .with_message(format!("Cannot add {:?} and {:?}", l, r)));
+    let (ok, res) = checked_u256(l, r, op);
+    let cost = native_gas(context.cost_table(), index, 0);
+    Ok(NativeResult::ok(cost, checked_result(ok, res)))
+}
+
+/// Pops `(l, r)` operands. The right operand is on the top of the stack.
+fn pop_operands(arguments: &mut VecDeque<Value>) -> PartialVMResult<(U256, U256)> {
+    let r = pop_u256(arguments)?;
+    let l = pop_u256(arguments)?;
+    Ok((l, r))
+}
+
+fn pop_u256(arguments: &mut VecDeque<Value>) -> PartialVMResult<U256> {
+    arguments
+        .pop_back()
+        .ok_or_else(|| {
+            PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                .with_message("Missing U256 argument.".to_owned())
+        })
+        .and_then(|arg| arg.value_as::<Struct>())
+        .and_then(unwrap_u256)
+}
+
+/// `(true, result)` or `(false, 0)` if the operation failed.
+fn checked_u256(l: U256, r: U256, op: fn(U256, U256) -> PartialVMResult<U256>) -> (bool, U256) {
+    match op(l, r) {
+        Ok(res) => (true, res),
+        Err(_) => (false, U256::zero()),
     }
+}
 
-    let cost = native_gas(context.cost_table(), PontNativeCostIndex::U256_ADD, 0);
-    Ok(NativeResult::ok(cost, smallvec![wrap_u256(res)]))
+/// Return values of the checked natives: the flag goes first, the result second.
+fn checked_result(ok: bool, res: U256) -> SmallVec<[Value; 1]> {
+    smallvec![Value::bool(ok), wrap_u256(res)]
+}
+
+/// Shifts by up to 255 bits. Bits shifted out are lost.
+fn shl_u256(val: U256, shift: u8) -> U256 {
+    val << shift as usize
+}
+
+fn shr_u256(val: U256, shift: u8) -> U256 {
+    val >> shift as usize
+}
+
+fn try_add(l: U256, r: U256) -> PartialVMResult<U256> {
+    l.checked_add(r)
+        .ok_or_else(|| arithmetic_error(format!("Cannot add {:?} and {:?}", l, r)))
+}
+
+fn try_sub(l: U256, r: U256) -> PartialVMResult<U256> {
+    l.checked_sub(r)
+        .ok_or_else(|| arithmetic_error(format!("Cannot sub {:?} from {:?}", r, l)))
+}
+
+fn try_mul(l: U256, r: U256) -> PartialVMResult<U256> {
+    l.checked_mul(r)
+        .ok_or_else(|| arithmetic_error(format!("Cannot mul {:?} and {:?}", l, r)))
+}
+
+fn try_div(l: U256, r: U256) -> PartialVMResult<U256> {
+    if r.is_zero() {
+        return Err(arithmetic_error(format!("Cannot div {:?} by {:?}", l, r)));
+    }
+    Ok(l.div(r))
+}
+
+fn try_mod(l: U256, r: U256) -> PartialVMResult<U256> {
+    if r.is_zero() {
+        return Err(arithmetic_error(format!("Cannot mod {:?} by {:?}", l, r)));
+    }
+    Ok(l % r)
+}
+
+fn try_pow(l: U256, r: U256) -> PartialVMResult<U256> {
+    l.checked_pow(r)
+        .ok_or_else(|| arithmetic_error(format!("Cannot pow {:?} to {:?}", l, r)))
+}
+
+fn compare_u256(l: U256, r: U256) -> u8 {
+    match l.cmp(&r) {
+        Ordering::Equal => 0,
+        Ordering::Less => 1,
+        Ordering::Greater => 2,
+    }
+}
+
+fn to_bytes(val: U256, big_endian: bool) -> Vec<u8> {
+    let mut bytes = vec![0; 32];
+    if big_endian {
+        val.to_big_endian(&mut bytes);
+    } else {
+        val.to_little_endian(&mut bytes);
+    }
+    bytes
+}
+
+fn from_bytes(bytes: &[u8], big_endian: bool) -> PartialVMResult<U256> {
+    if bytes.len() > 32 {
+        return Err(arithmetic_error(format!(
+            "Cannot convert {} bytes to u256",
+            bytes.len()
+        )));
+    }
+    Ok(if big_endian {
+        U256::from_big_endian(bytes)
+    } else {
+        U256::from_little_endian(bytes)
+    })
+}
+
+fn arithmetic_error(message: String) -> PartialVMError {
+    PartialVMError::new(StatusCode::ARITHMETIC_ERROR).with_message(message)
 }
 
 pub fn unwrap_u256(u256: Struct) -> PartialVMResult<U256> {
@@ -226,3 +587,139 @@ fn wrap_u256(val: U256) -> Value {
     val.to_little_endian(&mut bytes);
     Value::struct_(Struct::pack(vec![Value::vector_u8(bytes)]))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use move_vm_types::values::{values_impl::Struct, Value};
+    use super::{
+        checked_result, checked_u256, compare_u256, from_bytes, pop_operands, shl_u256, shr_u256,
+        to_bytes, try_add, try_div, try_mod, try_mul, try_pow, try_sub, unwrap_u256, wrap_u256,
+        U256,
+    };
+
+    #[test]
+    fn test_overflow_boundaries() {
+        let max = U256::max_value();
+        let one = U256::one();
+
+        assert_eq!(try_add(max - one, one).unwrap(), max);
+        try_add(max, one).unwrap_err();
+        assert_eq!(try_sub(one, one).unwrap(), U256::zero());
+        try_sub(U256::zero(), one).unwrap_err();
+        assert_eq!(try_mul(max, one).unwrap(), max);
+        try_mul(U256::from(2), (max >> 1) + one).unwrap_err();
+
+        try_div(max, U256::zero()).unwrap_err();
+        assert_eq!(try_mod(max, U256::from(10)).unwrap(), U256::from(5));
+        try_mod(max, U256::zero()).unwrap_err();
+
+        assert_eq!(try_pow(U256::from(2), U256::from(255)).unwrap(), one << 255);
+        try_pow(U256::from(2), U256::from(256)).unwrap_err();
+        assert_eq!(try_pow(max, U256::zero()).unwrap(), one);
+    }
+
+    #[test]
+    fn test_shifts() {
+        let max = U256::max_value();
+        let one = U256::one();
+
+        assert_eq!(shl_u256(one, 255), U256::from_big_endian(&[0x80]) << 248);
+        assert_eq!(shl_u256(max, 255), one << 255);
+        assert_eq!(shl_u256(one << 255, 1), U256::zero());
+        assert_eq!(shl_u256(max, 0), max);
+
+        assert_eq!(shr_u256(max, 255), one);
+        assert_eq!(shr_u256(one << 255, 255), one);
+        assert_eq!(shr_u256(max - one, 255), one);
+        assert_eq!(shr_u256(one, 1), U256::zero());
+        assert_eq!(shr_u256(max, 0), max);
+
+        assert_eq!(max & one, one);
+        assert_eq!(U256::zero() | one, one);
+        assert_eq!(max ^ max, U256::zero());
+    }
+
+    #[test]
+    fn test_compare() {
+        let max = U256::max_value();
+        let one = U256::one();
+
+        assert_eq!(compare_u256(one, one), 0);
+        assert_eq!(compare_u256(max, max), 0);
+        assert_eq!(compare_u256(U256::zero(), U256::zero()), 0);
+        assert_eq!(compare_u256(max - one, max), 1);
+        assert_eq!(compare_u256(U256::zero(), max), 1);
+        assert_eq!(compare_u256(max, max - one), 2);
+        assert_eq!(compare_u256(max, U256::zero()), 2);
+    }
+
+    #[test]
+    fn test_checked() {
+        let max = U256::max_value();
+        let one = U256::one();
+
+        assert_eq!(checked_u256(max, one, try_add), (false, U256::zero()));
+        assert_eq!(checked_u256(max - one, one, try_add), (true, max));
+        assert_eq!(
+            checked_u256(one, U256::from(2), try_add),
+            (true, U256::from(3))
+        );
+        assert_eq!(
+            checked_u256(one, U256::from(2), try_sub),
+            (false, U256::zero())
+        );
+        assert_eq!(checked_u256(U256::from(2), one, try_sub), (true, one));
+        assert_eq!(
+            checked_u256(U256::from(10), U256::from(2), try_div),
+            (true, U256::from(5))
+        );
+        assert_eq!(
+            checked_u256(max, U256::zero(), try_div),
+            (false, U256::zero())
+        );
+
+        let mut values = checked_result(false, U256::zero()).into_iter();
+        assert!(!values.next().unwrap().value_as::<bool>().unwrap());
+        let res = values.next().unwrap().value_as::<Struct>().unwrap();
+        assert_eq!(unwrap_u256(res).unwrap(), U256::zero());
+        assert!(values.next().is_none());
+
+        let mut values = checked_result(true, max).into_iter();
+        assert!(values.next().unwrap().value_as::<bool>().unwrap());
+        let res = values.next().unwrap().value_as::<Struct>().unwrap();
+        assert_eq!(unwrap_u256(res).unwrap(), max);
+    }
+
+    #[test]
+    fn test_pop_operands() {
+        let mut arguments =
+            VecDeque::from(vec![wrap_u256(U256::from(10)), wrap_u256(U256::from(2))]);
+        let (l, r) = pop_operands(&mut arguments).unwrap();
+        assert_eq!((l, r), (U256::from(10), U256::from(2)));
+        assert!(arguments.is_empty());
+        assert_eq!(checked_u256(l, r, try_sub), (true, U256::from(8)));
+
+        let mut arguments = VecDeque::from(vec![wrap_u256(U256::one())]);
+        pop_operands(&mut arguments).unwrap_err();
+
+        let mut arguments = VecDeque::from(vec![Value::u8(1), Value::u8(2)]);
+        pop_operands(&mut arguments).unwrap_err();
+    }
+
+    #[test]
+    fn test_bytes() {
+        let val = U256::from(0x0102);
+        let le = to_bytes(val, false);
+        let be = to_bytes(val, true);
+        assert_eq!(&le[..2], &[0x02, 0x01]);
+        assert_eq!(&be[30..], &[0x01, 0x02]);
+        assert_eq!(from_bytes(&le, false).unwrap(), val);
+        assert_eq!(from_bytes(&be, true).unwrap(), val);
+
+        assert_eq!(from_bytes(&[0x01, 0x02], true).unwrap(), val);
+        assert_eq!(from_bytes(&[0xff; 32], false).unwrap(), U256::max_value());
+        from_bytes(&[0; 33], true).unwrap_err();
+    }
+}
@@ -1,11 +1,13 @@
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 use anyhow::Error;
 use clap::Parser;
 use anyhow::Result;
+use serde::Deserialize;
 use url::Url;
 
-use pontem_client::PontemClient;
+use pontem_client::{PontemClient, SignParams};
 use crate::cmd::key::cli_entering_a_secret_phrase;
 use crate::wallet_key;
 use crate::wallet_key::WalletKey;
@@ -108,26 +110,9 @@ impl TryFrom<(&NodeAccessParams, PathBuf)> for Publish {
         let gas_limit = params
             .gas_limit
             .ok_or_else(|| anyhow!("Please specify gas limit"))?;
-        let mut url_to_node = params.url_to_node.clone();
-
-        let access = if params.secret_phrase {
-            // Request secret phrases
-            let secret = cli_entering_a_secret_phrase()?;
-            AccessType::SecretPhrase(secret)
-        } else if let Some(test_account_or_name_key) = &params.account {
-            match cli_name_to_key(test_account_or_name_key)? {
-                Some(WalletKey {
-                    secret_phrase,
-                    node_address,
-                }) => {
-                    url_to_node = node_address;
-                    AccessType::SecretPhrase(secret_phrase)
-                }
-                None => AccessType::TestAccount(test_account_or_name_key.to_owned()),
-            }
-        } else {
-            bail!("Specify name of key or name of test account or secret phrase")
-        };
+        let (access, node_address) =
+            access_type(params.account.as_deref(), params.secret_phrase)?;
+        let url_to_node = node_address.unwrap_or_else(|| params.url_to_node.clone());
 
         let client = PontemClient::new(url_to_node.as_str())?;
 
@@ -142,31 +127,217 @@ impl TryFrom<(&NodeAccessParams, PathBuf)> for Publish {
 
 impl Publish {
     fn file_type(&self) -> Result<FileType> {
-        let ext = self
-            .file_path
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .unwrap_or_default()
-            .to_string();
-
-        Ok(match ext.as_str() {
-            "pac" => FileType::Bundle,
-            "mv" => FileType::Module,
-            "mvt" => FileType::TX,
-            _ => bail!(
-                "pac or mv extension was expected\n{}",
-                self.file_path.display()
-            ),
-        })
+        file_type(&self.file_path)
     }
 
     fn file_path_as_str(&self) -> Result<&str> {
-        self.file_path
-            .to_str()
-            .ok_or_else(|| anyhow!("Error converting path to string"))
+        path_as_str(&self.file_path)
     }
 }
 
+/// Parameters for signing without connection to the node.
+#[derive(Parser, Debug)]
+pub struct SignParamsCmd {
+    /// Account from whom to sign. Test account name or name secret key.
+    /// Example: //Alice, alice, bob, NAME_WALLET_KEY...
+    #[clap(long = "account")]
+    account: Option<String>,
+
+    /// Secret phrase.
+    /// If a secret phrase is specified, you do not need to specify an account
+    #[clap(long = "secret", short)]
+    secret_phrase: bool,
+
+    /// Limitation of gas consumption per operation
+    #[clap(long = "gas", short)]
+    gas_limit: Option<u64>,
+
+    /// Account nonce
+    #[clap(long = "nonce")]
+    nonce: Option<u32>,
+
+    /// Genesis hash of the chain in hex
+    #[clap(long = "genesis-hash")]
+    genesis_hash: Option<String>,
+
+    /// Runtime spec version
+    #[clap(long = "spec-version")]
+    spec_version: Option<u32>,
+
+    /// Runtime transaction version
+    #[clap(long = "tx-version")]
+    transaction_version: Option<u32>,
+
+    /// Path to a JSON file with the chain parameters:
+    /// {"nonce": 0, "genesis_hash": "0x...", "spec_version": 1, "transaction_version": 1}.
+    /// Flags take precedence over the file
+    #[clap(long = "meta")]
+    meta: Option<PathBuf>,
+}
+
+/// Chain parameters from the metadata file.
+#[derive(Deserialize, Debug, Default)]
+struct SignMeta {
+    nonce: Option<u32>,
+    genesis_hash: Option<String>,
+    spec_version: Option<u32>,
+    transaction_version: Option<u32>,
+}
+
+pub struct Sign {
+    /// Client for signing. It does not connect to the node
+    client: PontemClient,
+
+    /// Path to the file to be signed
+    file_path: PathBuf,
+
+    /// Limitation of gas consumption per operation
+    gas_limit: u64,
+
+    /// Access type - by secret phrase or through a test account
+    access: AccessType,
+
+    /// Nonce, genesis hash and runtime versions
+    params: SignParams,
+}
+
+impl Sign {
+    /// Returns the signed extrinsic in hex.
+    pub fn apply(&self) -> Result<String> {
+        // Checking the file type before signing
+        file_type(&self.file_path)?;
+        let file_path = path_as_str(&self.file_path)?;
+
+        match &self.access {
+            AccessType::SecretPhrase(secret) => {
+                self.client
+                    .tx_mvm_sign(file_path, self.gas_limit, secret, &self.params)
+            }
+            AccessType::TestAccount(test_account) => {
+                self.client
+                    .tx_mvm_sign_dev(file_path, self.gas_limit, test_account, &self.params)
+            }
+        }
+    }
+}
+
+/// SignParamsCmd - Signing parameters
+/// PathBuf - The path to the file to be signed (*.mvt, *.mv, *.pac)
+impl TryFrom<(&SignParamsCmd, PathBuf)> for Sign {
+    type Error = Error;
+
+    fn try_from(value: (&SignParamsCmd, PathBuf)) -> std::result::Result<Self, Self::Error> {
+        let (params, file_path) = value;
+        let gas_limit = params
+            .gas_limit
+            .ok_or_else(|| anyhow!("Please specify gas limit"))?;
+
+        let meta = match &params.meta {
+            Some(path) => serde_json::from_str(&fs::read_to_string(path)?)
+                .map_err(|err| anyhow!("Failed to parse metadata file {:?}: {}", path, err))?,
+            None => SignMeta::default(),
+        };
+        let sign_params = SignParams {
+            nonce: params
+                .nonce
+                .or(meta.nonce)
+                .ok_or_else(|| anyhow!("Please specify nonce"))?,
+            genesis_hash: params
+                .genesis_hash
+                .clone()
+                .or(meta.genesis_hash)
+                .ok_or_else(|| anyhow!("Please specify genesis hash"))?,
+            spec_version: params
+                .spec_version
+                .or(meta.spec_version)
+                .ok_or_else(|| anyhow!("Please specify spec version"))?,
+            transaction_version: params
+                .transaction_version
+                .or(meta.transaction_version)
+                .ok_or_else(|| anyhow!("Please specify transaction version"))?,
+        };
+        ensure!(
+            is_hex_of_len(&sign_params.genesis_hash, 32),
+            "Genesis hash must be 32 bytes in hex: {}",
+            sign_params.genesis_hash
+        );
+
+        let (access, _) = access_type(params.account.as_deref(), params.secret_phrase)?;
+
+        Ok(Sign {
+            // The node address is not used for signing.
+            client: PontemClient::default(),
+            file_path,
+            gas_limit,
+            access,
+            params: sign_params,
+        })
+    }
+}
+
+/// Submitting the signed extrinsic from the file. Returns the extrinsic hash.
+pub fn submit(url_to_node: &Url, file_path: &Path) -> Result<String> {
+    let extrinsic = fs::read_to_string(file_path)
+        .map_err(|err| anyhow!("Failed to read {}: {}", file_path.display(), err))?;
+    let extrinsic = extrinsic.trim();
+    ensure!(
+        !extrinsic.is_empty() && hex::decode(extrinsic.trim_start_matches("0x")).is_ok(),
+        "{} does not contain a signed extrinsic in hex",
+        file_path.display()
+    );
+    PontemClient::new(url_to_node.as_str())?.tx_submit(extrinsic)
+}
+
+/// Checks that the value is `len` bytes in hex with an optional `0x` prefix.
+fn is_hex_of_len(value: &str, len: usize) -> bool {
+    hex::decode(value.trim_start_matches("0x"))
+        .map(|bytes| bytes.len() == len)
+        .unwrap_or(false)
+}
+
+/// Returns the access type and the node address of the wallet key.
+fn access_type(account: Option<&str>, secret_phrase: bool) -> Result<(AccessType, Option<Url>)> {
+    Ok(if secret_phrase {
+        // Request secret phrases
+        let secret = cli_entering_a_secret_phrase()?;
+        (AccessType::SecretPhrase(secret), None)
+    } else if let Some(test_account_or_name_key) = account {
+        match cli_name_to_key(test_account_or_name_key)? {
+            Some(WalletKey {
+                secret_phrase,
+                node_address,
+            }) => (AccessType::SecretPhrase(secret_phrase), Some(node_address)),
+            None => (
+                AccessType::TestAccount(test_account_or_name_key.to_owned()),
+                None,
+            ),
+        }
+    } else {
+        bail!("Specify name of key or name of test account or secret phrase")
+    })
+}
+
+fn file_type(file_path: &Path) -> Result<FileType> {
+    let ext = file_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_string();
+
+    Ok(match ext.as_str() {
+        "pac" => FileType::Bundle,
+        "mv" => FileType::Module,
+        "mvt" => FileType::TX,
+        _ => bail!("pac or mv extension was expected\n{}", file_path.display()),
+    })
+}
+
+fn path_as_str(file_path: &Path) -> Result<&str> {
+    file_path
+        .to_str()
+        .ok_or_else(|| anyhow!("Error converting path to string"))
+}
+
 /// Access type - by secret phrase or through a test account
 enum AccessType {
     SecretPhrase(String),
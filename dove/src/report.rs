@@ -0,0 +1,241 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+use serde::Serialize;
+use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
+use move_core_types::resolver::{ModuleResolver, ResourceResolver};
+use move_resource_viewer::MoveValueAnnotator;
+use resource_viewer::ser::{AnnotatedMoveStructValue, AnnotatedMoveValueWrapper};
+
+use crate::executor::{ExecutionResult, Executor};
+
+/// Report output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// JSON.
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(ReportFormat::Json),
+            _ => bail!("Unsupported report format '{}'. Expected: json", s),
+        }
+    }
+}
+
+/// Execution report: status, return values, write set and events.
+/// Changes are ordered by address and type, so the report is deterministic.
+#[derive(Serialize)]
+pub struct ExecutionReport {
+    /// `executed` or `failed`.
+    pub status: &'static str,
+    /// Execution error description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Gas used by the execution.
+    pub gas_used: u64,
+    /// Values returned by the function.
+    pub return_values: Vec<AnnotatedMoveValueWrapper>,
+    /// Changed resources.
+    pub resources: Vec<ResourceChange>,
+    /// Changed modules.
+    pub modules: Vec<ModuleChange>,
+    /// Emitted events.
+    pub events: Vec<EventReport>,
+}
+
+/// Kind of the storage change.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Published,
+    Changed,
+    Deleted,
+}
+
+/// Resource change.
+#[derive(Serialize)]
+pub struct ResourceChange {
+    /// Account address.
+    pub address: String,
+    /// Resource type.
+    #[serde(rename = "type")]
+    pub tp: String,
+    /// Kind of the change.
+    pub change: ChangeKind,
+    /// Value before the execution.
+    pub before: Option<AnnotatedMoveStructValue>,
+    /// Value after the execution.
+    pub after: Option<AnnotatedMoveStructValue>,
+}
+
+/// Module change.
+#[derive(Serialize)]
+pub struct ModuleChange {
+    /// Module address.
+    pub address: String,
+    /// Module name.
+    pub name: String,
+    /// Kind of the change.
+    pub change: ChangeKind,
+    /// Bytecode size before the execution.
+    pub size_before: Option<usize>,
+    /// Bytecode size after the execution.
+    pub size_after: Option<usize>,
+}
+
+/// Emitted event.
+#[derive(Serialize)]
+pub struct EventReport {
+    /// Event handle key in hex.
+    pub key: String,
+    /// Sequence number of the event.
+    pub sequence_number: u64,
+    /// Decoded payload.
+    pub data: AnnotatedMoveValueWrapper,
+}
+
+impl ExecutionReport {
+    /// Builds the report of the execution result.
+    /// Must be called before the effects are committed to the storage.
+    pub fn new<S>(
+        executor: &Executor<S>,
+        result: &ExecutionResult,
+        returns: &[TypeTag],
+    ) -> Result<ExecutionReport>
+    where
+        S: ModuleResolver<Error = Error> + ResourceResolver<Error = Error>,
+    {
+        let effects = match &result.effects {
+            Ok(effects) => effects,
+            Err(err) => {
+                return Ok(ExecutionReport {
+                    status: "failed",
+                    error: Some(executor.explain_error(err)),
+                    gas_used: result.gas_used,
+                    return_values: vec![],
+                    resources: vec![],
+                    modules: vec![],
+                    events: vec![],
+                })
+            }
+        };
+
+        let state = executor.state();
+        let annotator = MoveValueAnnotator::new(state);
+        let annotate = |tag: &StructTag, blob: Option<Vec<u8>>| -> Result<_> {
+            blob.map(|blob| {
+                annotator
+                    .view_resource(tag, &blob)
+                    .map(AnnotatedMoveStructValue)
+            })
+            .transpose()
+        };
+
+        let mut resources = vec![];
+        let mut modules = vec![];
+        for (addr, account) in &effects.change_set.accounts {
+            for (tag, blob) in &account.resources {
+                let before = state.get_resource(addr, tag)?;
+                resources.push(ResourceChange {
+                    address: addr.to_hex_literal(),
+                    tp: tag.to_string(),
+                    change: change_kind(before.is_some(), blob.is_some()),
+                    before: annotate(tag, before)?,
+                    after: annotate(tag, blob.clone())?,
+                });
+            }
+
+            for (name, blob) in &account.modules {
+                let before = state.get_module(&ModuleId::new(*addr, name.clone()))?;
+                modules.push(ModuleChange {
+                    address: addr.to_hex_literal(),
+                    name: name.to_string(),
+                    change: change_kind(before.is_some(), blob.is_some()),
+                    size_before: before.map(|blob| blob.len()),
+                    size_after: blob.as_ref().map(|blob| blob.len()),
+                });
+            }
+        }
+
+        let events = effects
+            .events
+            .iter()
+            .map(|(key, sequence_number, tp, data)| {
+                Ok(EventReport {
+                    key: format!("0x{}", hex::encode(key)),
+                    sequence_number: *sequence_number,
+                    data: AnnotatedMoveValueWrapper {
+                        tp: tp.to_string(),
+                        value: annotator.view_value(tp, data)?,
+                    },
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(ExecutionReport {
+            status: "executed",
+            error: None,
+            gas_used: result.gas_used,
+            return_values: annotate_values(executor, returns, &result.return_values)?,
+            resources,
+            modules,
+            events,
+        })
+    }
+}
+
+/// Decodes values returned by the function.
+pub fn annotate_values<S>(
+    executor: &Executor<S>,
+    returns: &[TypeTag],
+    values: &[Vec<u8>],
+) -> Result<Vec<AnnotatedMoveValueWrapper>>
+where
+    S: ModuleResolver<Error = Error> + ResourceResolver<Error = Error>,
+{
+    if values.is_empty() {
+        return Ok(vec![]);
+    }
+    ensure!(
+        returns.len() == values.len(),
+        "The function returned {} values, {} are declared",
+        values.len(),
+        returns.len()
+    );
+
+    let annotator = MoveValueAnnotator::new(executor.state());
+    returns
+        .iter()
+        .zip(values)
+        .map(|(tp, value)| {
+            Ok(AnnotatedMoveValueWrapper {
+                tp: tp.to_string(),
+                value: annotator.view_value(tp, value)?,
+            })
+        })
+        .collect()
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ChangeKind::Published => "published",
+            ChangeKind::Changed => "changed",
+            ChangeKind::Deleted => "deleted",
+        })
+    }
+}
+
+fn change_kind(before: bool, after: bool) -> ChangeKind {
+    match (before, after) {
+        (false, _) => ChangeKind::Published,
+        (true, true) => ChangeKind::Changed,
+        (true, false) => ChangeKind::Deleted,
+    }
+}
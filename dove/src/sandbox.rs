@@ -0,0 +1,255 @@
+use std::collections::BTreeSet;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use move_cli::DEFAULT_STORAGE_DIR;
+use move_core_types::account_address::AccountAddress;
+
+use crate::context::Context;
+
+/// Directory of the named sandboxes in the project root.
+pub const SANDBOXES_DIR: &str = "sandboxes";
+/// Directory of the sandbox snapshots in the project root.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
+
+/// Returns the storage directory of the named sandbox or the default project storage.
+pub fn storage_dir(ctx: &Context, sandbox: Option<&str>) -> Result<PathBuf> {
+    Ok(match sandbox {
+        Some(name) => {
+            check_name(name)?;
+            ctx.project_root_dir.join(SANDBOXES_DIR).join(name)
+        }
+        None => ctx.project_root_dir.join(DEFAULT_STORAGE_DIR),
+    })
+}
+
+/// Returns the directory of the snapshot.
+pub fn snapshot_dir(ctx: &Context, snapshot: &str) -> Result<PathBuf> {
+    check_name(snapshot)?;
+    Ok(ctx.project_root_dir.join(SNAPSHOTS_DIR).join(snapshot))
+}
+
+fn check_name(name: &str) -> Result<()> {
+    ensure!(
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_'),
+        "Invalid name '{}'. Use letters, digits, '-' and '_'.",
+        name
+    );
+    Ok(())
+}
+
+/// Returns sorted names of the subdirectories.
+pub fn list_dirs(dir: &Path) -> Result<Vec<String>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    let mut names = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    Ok(names)
+}
+
+/// Replaces the `to` directory with a copy of the `from` directory.
+/// Files are copied rather than hard-linked: the storage rewrites resource files in place,
+/// so a hard link would let the sandbox modify the snapshot.
+pub fn copy_storage(from: &Path, to: &Path) -> Result<()> {
+    if to.exists() {
+        fs::remove_dir_all(to)?;
+    }
+    copy_dir(from, to)
+}
+
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Difference of two storage trees.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Change {
+    Added(StorageItem),
+    Removed(StorageItem),
+    Changed(StorageItem),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Added(item) => write!(f, "+ {}", item),
+            Change::Removed(item) => write!(f, "- {}", item),
+            Change::Changed(item) => write!(f, "~ {}", item),
+        }
+    }
+}
+
+/// File of the storage tree.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StorageItem {
+    /// `<address>/resources/<struct tag>.bcs`
+    Resource {
+        address: AccountAddress,
+        tag: String,
+    },
+    /// `<address>/modules/<name>.mv`
+    Module {
+        address: AccountAddress,
+        name: String,
+    },
+    /// Any other file, e.g. events.
+    File(PathBuf),
+}
+
+impl StorageItem {
+    fn new(path: &Path) -> StorageItem {
+        let parts = path
+            .iter()
+            .map(|part| part.to_string_lossy())
+            .collect::<Vec<_>>();
+        if let [address, kind, file] = parts.as_slice() {
+            let address = AccountAddress::from_hex_literal(address);
+            match (address, kind.as_ref(), file.rsplit_once('.')) {
+                (Ok(address), "resources", Some((tag, "bcs"))) => {
+                    return StorageItem::Resource {
+                        address,
+                        tag: tag.to_string(),
+                    }
+                }
+                (Ok(address), "modules", Some((name, "mv"))) => {
+                    return StorageItem::Module {
+                        address,
+                        name: name.to_string(),
+                    }
+                }
+                _ => {}
+            }
+        }
+        StorageItem::File(path.to_owned())
+    }
+}
+
+impl Display for StorageItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageItem::Resource { address, tag } => {
+                write!(f, "{} resource {}", address.to_hex_literal(), tag)
+            }
+            StorageItem::Module { address, name } => {
+                write!(f, "{} module {}", address.to_hex_literal(), name)
+            }
+            StorageItem::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Compares the files of two storage trees.
+pub fn diff(from: &Path, to: &Path) -> Result<Vec<Change>> {
+    let mut from_files = BTreeSet::new();
+    collect_files(from, Path::new(""), &mut from_files)?;
+    let mut to_files = BTreeSet::new();
+    collect_files(to, Path::new(""), &mut to_files)?;
+
+    let mut changes = vec![];
+    for path in from_files.union(&to_files) {
+        let change = match (from_files.contains(path), to_files.contains(path)) {
+            (true, false) => Change::Removed(StorageItem::new(path)),
+            (false, true) => Change::Added(StorageItem::new(path)),
+            _ if fs::read(from.join(path))? != fs::read(to.join(path))? => {
+                Change::Changed(StorageItem::new(path))
+            }
+            _ => continue,
+        };
+        changes.push(change);
+    }
+    Ok(changes)
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut BTreeSet<PathBuf>) -> Result<()> {
+    let path = root.join(dir);
+    if !path.exists() {
+        return Ok(());
+    }
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let relative = dir.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            collect_files(root, &relative, files)?;
+        } else {
+            files.insert(relative);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+    use move_core_types::account_address::AccountAddress;
+
+    use super::{copy_storage, diff, Change, StorageItem};
+
+    fn write(root: &Path, path: &str, content: &[u8]) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_snapshot_diff() {
+        let dir = std::env::temp_dir().join("dove_test_snapshot_diff");
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        let sandbox = dir.join("sandbox");
+        let snapshot = dir.join("snapshot");
+        write(&sandbox, "0x1/resources/0x1::Store::U64.bcs", &[1]);
+        write(&sandbox, "0x1/modules/Store.mv", &[0]);
+
+        copy_storage(&sandbox, &snapshot).unwrap();
+        assert!(diff(&snapshot, &sandbox).unwrap().is_empty());
+
+        write(&sandbox, "0x1/resources/0x1::Store::U64.bcs", &[2]);
+        write(&sandbox, "0x2/resources/0x1::Store::U64.bcs", &[3]);
+        fs::remove_file(sandbox.join("0x1/modules/Store.mv")).unwrap();
+        let changes = diff(&snapshot, &sandbox).unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                Change::Removed(StorageItem::Module {
+                    address: AccountAddress::ONE,
+                    name: "Store".to_string()
+                }),
+                Change::Changed(StorageItem::Resource {
+                    address: AccountAddress::ONE,
+                    tag: "0x1::Store::U64".to_string()
+                }),
+                Change::Added(StorageItem::Resource {
+                    address: AccountAddress::from_hex_literal("0x2").unwrap(),
+                    tag: "0x1::Store::U64".to_string()
+                }),
+            ]
+        );
+        assert_eq!(changes[1].to_string(), "~ 0x1 resource 0x1::Store::U64");
+
+        copy_storage(&snapshot, &sandbox).unwrap();
+        assert!(diff(&snapshot, &sandbox).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
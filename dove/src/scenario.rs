@@ -0,0 +1,588 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use move_binary_format::errors::VMError;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::TypeTag;
+use move_core_types::vm_status::StatusCode;
+use move_package::source_package::parsed_manifest::AddressDeclarations;
+use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue, MoveValueAnnotator};
+
+use crate::call::cmd::CallDeclarationCmd;
+use crate::call::fn_call::Config;
+use crate::call::make_transaction;
+use crate::call::parser::parse_tp_param;
+use crate::cmd::view::parse_account;
+use crate::context::Context;
+use crate::executor::Executor;
+
+/// Ordered list of calls executed against one sandbox storage.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// Gas budget of each step.
+    #[serde(default = "default_gas_budget")]
+    pub gas_budget: u64,
+    /// Steps in execution order.
+    #[serde(rename = "step", default)]
+    pub steps: Vec<Step>,
+}
+
+/// Scenario step.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Step {
+    /// Step name. Default: the call.
+    pub name: Option<String>,
+    /// Call in the `dove run` syntax, e.g. `Store::store_u64(Demo, 100)`.
+    pub call: String,
+    /// Move package name.
+    pub package: Option<String>,
+    /// Expected outcome. By default the step must succeed.
+    #[serde(default)]
+    pub expect: Expect,
+}
+
+/// Expected outcome of the step.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Expect {
+    /// `false` if the execution must fail.
+    #[serde(default = "default_success")]
+    pub success: bool,
+    /// Expected abort code. Implies a failure.
+    pub abort_code: Option<u64>,
+    /// Types of the events that must be emitted.
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Resources checked after the step.
+    #[serde(default)]
+    pub resources: Vec<ExpectedResource>,
+}
+
+impl Default for Expect {
+    fn default() -> Self {
+        Expect {
+            success: true,
+            abort_code: None,
+            events: vec![],
+            resources: vec![],
+        }
+    }
+}
+
+/// Expected resource state.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedResource {
+    /// Account address, alias or ss58 address.
+    pub address: String,
+    /// Resource type, e.g. `Demo::Store::U64`.
+    #[serde(rename = "type")]
+    pub tp: String,
+    /// `false` if the resource must not exist.
+    #[serde(default = "default_success")]
+    pub exists: bool,
+    /// Expected field values keyed by the field path, e.g. `val` or `info.items[2]`.
+    #[serde(default)]
+    pub fields: BTreeMap<String, toml::Value>,
+}
+
+fn default_gas_budget() -> u64 {
+    1_000_000_000
+}
+
+fn default_success() -> bool {
+    true
+}
+
+impl Scenario {
+    /// Loads the scenario from a TOML file.
+    pub fn load(path: &Path) -> Result<Scenario> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read scenario {:?}: {}", path, err))?;
+        toml::from_str(&content)
+            .map_err(|err| anyhow!("Failed to parse scenario {:?}: {}", path, err))
+    }
+}
+
+/// Scenario execution report.
+#[derive(Serialize)]
+pub struct ScenarioReport {
+    /// Scenario name.
+    pub name: String,
+    /// Number of passed steps.
+    pub passed: usize,
+    /// Number of failed steps.
+    pub failed: usize,
+    /// Step results in execution order.
+    pub steps: Vec<StepReport>,
+}
+
+/// Step result.
+#[derive(Serialize)]
+pub struct StepReport {
+    /// Step name.
+    pub name: String,
+    /// Executed call.
+    pub call: String,
+    /// `true` if all expectations are met.
+    pub passed: bool,
+    /// `executed` or `failed`.
+    pub status: &'static str,
+    /// Abort code if the execution aborted.
+    pub abort_code: Option<u64>,
+    /// Gas used by the execution.
+    pub gas_used: u64,
+    /// Execution time in seconds.
+    pub time: f64,
+    /// Unmet expectations.
+    pub failures: Vec<String>,
+}
+
+/// Runs the scenario steps in order against the sandbox storage in `storage_dir`.
+/// Effects of the successful steps are committed, so later steps see them.
+pub fn run_scenario(
+    ctx: &Context,
+    name: String,
+    scenario: &Scenario,
+    storage_dir: &Path,
+) -> Result<ScenarioReport> {
+    if storage_dir.exists() {
+        fs::remove_dir_all(storage_dir)?;
+    }
+    let executor = Executor::new(ctx, storage_dir)?;
+
+    let steps =
+        scenario
+            .steps
+            .iter()
+            .map(|step| {
+                let start = Instant::now();
+                let mut report = run_step(ctx, &executor, step, scenario.gas_budget)
+                    .unwrap_or_else(|err| StepReport {
+                        name: step_name(step),
+                        call: step.call.clone(),
+                        passed: false,
+                        status: "failed",
+                        abort_code: None,
+                        gas_used: 0,
+                        time: 0.0,
+                        failures: vec![err.to_string()],
+                    });
+                report.time = start.elapsed().as_secs_f64();
+                report
+            })
+            .collect::<Vec<_>>();
+
+    let passed = steps.iter().filter(|step| step.passed).count();
+    Ok(ScenarioReport {
+        name,
+        passed,
+        failed: steps.len() - passed,
+        steps,
+    })
+}
+
+fn step_name(step: &Step) -> String {
+    step.name.clone().unwrap_or_else(|| step.call.clone())
+}
+
+fn run_step(
+    ctx: &Context,
+    executor: &Executor,
+    step: &Step,
+    gas_budget: u64,
+) -> Result<StepReport> {
+    let addr_map = ctx.address_declarations();
+    let tx = make_transaction(
+        ctx,
+        CallDeclarationCmd::new(step.call.clone(), step.package.clone()),
+        Config::for_run(),
+    )?;
+    let result = executor.execute(tx, gas_budget)?;
+
+    let mut failures = vec![];
+    let expect = &step.expect;
+    let expect_success = expect.success && expect.abort_code.is_none();
+    let (status, abort_code) = match result.effects {
+        Ok(effects) => {
+            if !expect_success {
+                failures.push("Expected the execution to fail, but it succeeded".to_string());
+            }
+            let mut emitted = effects
+                .events
+                .iter()
+                .map(|(_, _, tp, _)| tp.clone())
+                .collect::<Vec<_>>();
+            for event in &expect.events {
+                let tp = parse_tp_param(&addr_map, event)?;
+                match emitted.iter().position(|emitted| emitted == &tp) {
+                    Some(idx) => {
+                        emitted.remove(idx);
+                    }
+                    None => failures.push(format!("Event {} was not emitted", event)),
+                }
+            }
+            executor.commit(effects)?;
+            ("executed", None)
+        }
+        Err(err) => {
+            let abort_code = abort_code(&err);
+            if expect_success {
+                failures.push(executor.explain_error(&err));
+            } else if let Some(expected) = expect.abort_code {
+                if abort_code != Some(expected) {
+                    failures.push(format!(
+                        "Expected abort code {}, got: {}",
+                        expected,
+                        executor.explain_error(&err)
+                    ));
+                }
+            }
+            ("failed", abort_code)
+        }
+    };
+
+    for resource in &expect.resources {
+        if let Err(err) = check_resource(ctx, executor, resource) {
+            failures.push(err.to_string());
+        }
+    }
+
+    Ok(StepReport {
+        name: step_name(step),
+        call: step.call.clone(),
+        passed: failures.is_empty(),
+        status,
+        abort_code,
+        gas_used: result.gas_used,
+        time: 0.0,
+        failures,
+    })
+}
+
+fn abort_code(err: &VMError) -> Option<u64> {
+    if err.major_status() == StatusCode::ABORTED {
+        err.sub_status()
+    } else {
+        None
+    }
+}
+
+fn check_resource(ctx: &Context, executor: &Executor, expected: &ExpectedResource) -> Result<()> {
+    let addr_map = ctx.address_declarations();
+    let address = parse_account(&addr_map, &expected.address)?;
+    let tag = match parse_tp_param(&addr_map, &expected.tp)? {
+        TypeTag::Struct(tag) => tag,
+        _ => bail!("Resource type expected: {}", expected.tp),
+    };
+
+    let blob = executor.state().get_resource_bytes(address, tag.clone())?;
+    let blob = match (blob, expected.exists) {
+        (Some(blob), true) => blob,
+        (None, false) => return Ok(()),
+        (Some(_), false) => bail!("Resource {} exists at {}", expected.tp, expected.address),
+        (None, true) => bail!("Resource {} not found at {}", expected.tp, expected.address),
+    };
+
+    let value = MoveValueAnnotator::new(executor.state()).view_resource(&tag, &blob)?;
+    let mismatches = field_mismatches(&addr_map, &expected.fields, &value);
+    ensure!(
+        mismatches.is_empty(),
+        "Resource {} at {}:\n    {}",
+        expected.tp,
+        expected.address,
+        mismatches.join("\n    ")
+    );
+    Ok(())
+}
+
+/// Compares the expected field values with the resource fields.
+fn field_mismatches(
+    addr_map: &AddressDeclarations,
+    expected: &BTreeMap<String, toml::Value>,
+    value: &AnnotatedMoveStruct,
+) -> Vec<String> {
+    let mut fields = BTreeMap::new();
+    flatten_struct("", value, &mut fields);
+
+    expected
+        .iter()
+        .filter_map(|(path, expected)| match fields.get(path) {
+            Some(actual) if value_matches(addr_map, expected, actual) => None,
+            Some(actual) => Some(format!("{}: expected {}, got {}", path, expected, actual)),
+            None => Some(format!("{}: field not found", path)),
+        })
+        .collect()
+}
+
+/// Collects field values keyed by the field path.
+/// Values are rendered like `dove call` return values: `u128` as a string, addresses and
+/// `vector<u8>` as hex literals.
+fn flatten_struct(path: &str, value: &AnnotatedMoveStruct, fields: &mut BTreeMap<String, Value>) {
+    for (name, value) in &value.value {
+        let path = if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", path, name)
+        };
+        flatten_value(&path, value, fields);
+    }
+}
+
+fn flatten_value(path: &str, value: &AnnotatedMoveValue, fields: &mut BTreeMap<String, Value>) {
+    match value {
+        AnnotatedMoveValue::Struct(value) => flatten_struct(path, value, fields),
+        AnnotatedMoveValue::Vector(_, items) => {
+            for (idx, item) in items.iter().enumerate() {
+                flatten_value(&format!("{}[{}]", path, idx), item, fields);
+            }
+        }
+        AnnotatedMoveValue::Bytes(bytes) => {
+            for (idx, byte) in bytes.iter().enumerate() {
+                fields.insert(format!("{}[{}]", path, idx), Value::from(*byte));
+            }
+        }
+        _ => {}
+    }
+    fields.insert(path.to_string(), to_json(value));
+}
+
+fn to_json(value: &AnnotatedMoveValue) -> Value {
+    match value {
+        AnnotatedMoveValue::U8(val) => Value::from(*val),
+        AnnotatedMoveValue::U64(val) => Value::from(*val),
+        AnnotatedMoveValue::U128(val) => Value::String(val.to_string()),
+        AnnotatedMoveValue::Bool(val) => Value::Bool(*val),
+        AnnotatedMoveValue::Address(val) => Value::String(val.to_hex_literal()),
+        AnnotatedMoveValue::Bytes(bytes) => Value::String(format!("0x{}", hex::encode(bytes))),
+        AnnotatedMoveValue::Vector(_, items) => Value::Array(items.iter().map(to_json).collect()),
+        AnnotatedMoveValue::Struct(val) => Value::Object(
+            val.value
+                .iter()
+                .map(|(name, field)| (name.to_string(), to_json(field)))
+                .collect(),
+        ),
+    }
+}
+
+/// Compares the expected TOML value with the field value by type:
+/// integers by value, addresses by the resolved account and hex literals case-insensitively.
+fn value_matches(addr_map: &AddressDeclarations, expected: &toml::Value, actual: &Value) -> bool {
+    match (expected, actual) {
+        (toml::Value::Integer(expected), Value::Number(actual)) => {
+            u64::try_from(*expected).ok() == actual.as_u64()
+        }
+        (toml::Value::Integer(expected), Value::String(actual)) => {
+            u128::try_from(*expected).ok() == actual.parse::<u128>().ok()
+        }
+        (toml::Value::String(expected), Value::Number(actual)) => {
+            expected.parse::<u64>().ok() == actual.as_u64()
+        }
+        (toml::Value::String(expected), Value::String(actual)) => {
+            expected.eq_ignore_ascii_case(actual)
+                || matches!(
+                    (expected.parse::<u128>(), actual.parse::<u128>()),
+                    (Ok(expected), Ok(actual)) if expected == actual
+                )
+                || matches!(
+                    (parse_account(addr_map, expected), AccountAddress::from_hex_literal(actual)),
+                    (Ok(expected), Ok(actual)) if expected == actual
+                )
+        }
+        (toml::Value::Boolean(expected), Value::Bool(actual)) => expected == actual,
+        (toml::Value::Array(expected), Value::Array(actual)) => {
+            expected.len() == actual.len()
+                && expected
+                    .iter()
+                    .zip(actual)
+                    .all(|(expected, actual)| value_matches(addr_map, expected, actual))
+        }
+        (toml::Value::Array(expected), Value::String(actual)) if actual.starts_with("0x") => {
+            hex::decode(&actual[2..]).map_or(false, |bytes| {
+                expected.len() == bytes.len()
+                    && expected
+                        .iter()
+                        .zip(bytes)
+                        .all(|(expected, byte)| expected.as_integer() == Some(i64::from(byte)))
+            })
+        }
+        _ => false,
+    }
+}
+
+impl ScenarioReport {
+    /// Renders the report in the JUnit XML format.
+    pub fn to_junit(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str(&format!(
+            "<testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" time=\"{:.3}\">\n",
+            escape(&self.name),
+            self.steps.len(),
+            self.failed,
+            self.steps.iter().map(|step| step.time).sum::<f64>()
+        ));
+        for step in &self.steps {
+            xml.push_str(&format!(
+                "  <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\">\n",
+                escape(&step.name),
+                escape(&self.name),
+                step.time
+            ));
+            if !step.passed {
+                xml.push_str(&format!(
+                    "    <failure message=\"{}\">{}</failure>\n",
+                    escape(
+                        step.failures
+                            .first()
+                            .map(String::as_str)
+                            .unwrap_or_default()
+                    ),
+                    escape(&step.failures.join("\n"))
+                ));
+            }
+            xml.push_str("  </testcase>\n");
+        }
+        xml.push_str("</testsuite>\n");
+        xml
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use move_binary_format::file_format::AbilitySet;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::StructTag;
+    use move_package::source_package::parsed_manifest::{AddressDeclarations, NamedAddress};
+    use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue};
+
+    use super::{escape, field_mismatches, Scenario, ScenarioReport, StepReport};
+
+    #[test]
+    fn test_parse_scenario() {
+        let scenario: Scenario = toml::from_str(
+            r#"
+            [[step]]
+            call = "Store::store_u64(Demo, 100)"
+
+            [[step.expect.resources]]
+            address = "Demo"
+            type = "Demo::Store::U64"
+            fields = { val = 100 }
+
+            [[step]]
+            name = "abort"
+            call = "one_param(false)"
+            expect = { abort_code = 2 }
+            "#,
+        )
+        .unwrap();
+        assert_eq!(scenario.steps.len(), 2);
+        assert!(scenario.steps[0].expect.success);
+        assert_eq!(scenario.steps[0].expect.resources[0].fields.len(), 1);
+        assert_eq!(scenario.steps[1].expect.abort_code, Some(2));
+    }
+
+    #[test]
+    fn test_field_mismatches() {
+        let demo = AccountAddress::from_hex_literal("0x42").unwrap();
+        let mut addr_map = AddressDeclarations::new();
+        addr_map.insert(NamedAddress::from("Demo"), Some(demo));
+
+        let value = AnnotatedMoveStruct {
+            abilities: AbilitySet::EMPTY,
+            type_: StructTag {
+                address: demo,
+                module: Identifier::new("Store").unwrap(),
+                name: Identifier::new("Info").unwrap(),
+                type_params: vec![],
+            },
+            value: vec![
+                (Identifier::new("small").unwrap(), AnnotatedMoveValue::U8(5)),
+                (
+                    Identifier::new("big").unwrap(),
+                    AnnotatedMoveValue::U128(u128::MAX),
+                ),
+                (
+                    Identifier::new("owner").unwrap(),
+                    AnnotatedMoveValue::Address(demo),
+                ),
+                (
+                    Identifier::new("data").unwrap(),
+                    AnnotatedMoveValue::Bytes(vec![0xab, 1]),
+                ),
+            ],
+        };
+
+        let expected: BTreeMap<String, toml::Value> = toml::from_str(
+            r#"
+            small = 5
+            big = "340282366920938463463374607431768211455"
+            owner = "Demo"
+            data = "0xAB01"
+            "data[1]" = 1
+            "#,
+        )
+        .unwrap();
+        assert!(field_mismatches(&addr_map, &expected, &value).is_empty());
+
+        let expected: BTreeMap<String, toml::Value> = toml::from_str(
+            r#"
+            small = 6
+            owner = "0x1"
+            data = [171, 2]
+            "data[2]" = 0
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            field_mismatches(&addr_map, &expected, &value),
+            vec![
+                "data: expected [171, 2], got \"0xab01\"".to_string(),
+                "data[2]: field not found".to_string(),
+                "owner: expected \"0x1\", got \"0x42\"".to_string(),
+                "small: expected 6, got 5".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_junit() {
+        let report = ScenarioReport {
+            name: "demo".to_string(),
+            passed: 0,
+            failed: 1,
+            steps: vec![StepReport {
+                name: "store <u64>".to_string(),
+                call: "store()".to_string(),
+                passed: false,
+                status: "failed",
+                abort_code: Some(1),
+                gas_used: 10,
+                time: 0.0,
+                failures: vec!["Expected \"ok\"".to_string()],
+            }],
+        };
+        let xml = report.to_junit();
+        assert!(xml.contains("tests=\"1\" failures=\"1\""));
+        assert!(xml.contains("<testcase name=\"store &lt;u64&gt;\""));
+        assert!(xml.contains("<failure message=\"Expected &quot;ok&quot;\">"));
+        assert_eq!(escape("a&b"), "a&amp;b");
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use serde::Deserialize;
+use serde_json::Value;
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::CompiledModule;
+use move_cli::sandbox::utils::on_disk_state_view::OnDiskStateView;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::{ModuleId, StructTag, TypeTag};
+use move_core_types::value::MoveValue;
+use lang::bytecode::info::Type;
+use net::{Block, Net};
+
+use crate::call::bytecode::DoveBytecode;
+use crate::call::decode::decode_value;
+use crate::call::json::prepare_json_arg;
+use crate::call::parser::parse_tp_param;
+use crate::cmd::view::parse_account;
+use crate::context::Context;
+
+/// Declarative sandbox state.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct StateFile {
+    /// Resources written to the storage.
+    #[serde(default)]
+    pub resources: Vec<ResourceFixture>,
+}
+
+/// Resource of the state file.
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct ResourceFixture {
+    /// Resource owner: an address, a Move.toml alias or an ss58 address.
+    pub account: String,
+    /// Resource type, e.g. `Demo::Store::U64`.
+    #[serde(rename = "type")]
+    pub tp: String,
+    /// Resource fields in the `--args-file` format, e.g. `{"val": 100}`.
+    pub value: Value,
+}
+
+impl StateFile {
+    /// Loads the state file in JSON format.
+    pub fn load(path: &Path) -> Result<StateFile> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| anyhow!("Failed to read {}: {}", path.display(), err))?;
+        serde_json::from_str(&content)
+            .map_err(|err| anyhow!("Failed to parse {}: {}", path.display(), err))
+    }
+
+    /// BCS-encodes the resources using struct layouts of the project bytecode.
+    pub fn encode(&self, ctx: &Context) -> Result<Vec<(AccountAddress, StructTag, Vec<u8>)>> {
+        let addr_map = ctx.address_declarations();
+        let structs = DoveBytecode::new(ctx);
+        self.resources
+            .iter()
+            .enumerate()
+            .map(|(idx, resource)| {
+                let path = format!("$.resources[{}]", idx);
+                let address = parse_account(&addr_map, &resource.account)
+                    .map_err(|err| anyhow!("{}.account: {}", path, err))?;
+                let tag = match parse_tp_param(&addr_map, &resource.tp)
+                    .map_err(|err| anyhow!("{}.type: {}", path, err))?
+                {
+                    TypeTag::Struct(tag) => tag,
+                    _ => bail!("{}.type: resource type expected: {}", path, resource.tp),
+                };
+
+                let tp = Type::from(&TypeTag::Struct(tag.clone()));
+                let value = prepare_json_arg(
+                    &tp,
+                    &resource.value,
+                    &format!("{}.value", path),
+                    &addr_map,
+                    &structs,
+                )?;
+                let blob = bcs::to_bytes(&MoveValue::from(value))?;
+                // The blob must match the runtime layout of the resource.
+                decode_value(&tp, &blob, &structs)
+                    .map_err(|err| anyhow!("{}.value: {}", path, err))?;
+                Ok((address, tag, blob))
+            })
+            .collect()
+    }
+}
+
+/// Writes the resources of the state file to the sandbox storage.
+/// Existing resources of the same type are overwritten.
+/// Returns the number of written resources.
+pub fn seed_state(ctx: &Context, state: &OnDiskStateView, file: &StateFile) -> Result<usize> {
+    let resources = file.encode(ctx)?;
+    for (address, tag, blob) in &resources {
+        state.save_resource(*address, tag.clone(), blob)?;
+    }
+    Ok(resources.len())
+}
+
+/// Loads the modules with their transitive dependencies from the node.
+/// Each level of the dependency tree is loaded in one request.
+/// Fails if any module is missing, since the pulled modules wouldn't link.
+pub fn pull_modules(
+    net: &dyn Net,
+    module_ids: Vec<ModuleId>,
+    height: &Option<Block>,
+) -> Result<BTreeMap<ModuleId, Vec<u8>>> {
+    let mut modules = BTreeMap::new();
+    let mut next = module_ids;
+    loop {
+        next.sort();
+        next.dedup();
+        next.retain(|id| !modules.contains_key(id));
+        if next.is_empty() {
+            return Ok(modules);
+        }
+
+        let loaded = net.get_modules(&next, height)?;
+        let mut deps = vec![];
+        for (id, bytes) in next.into_iter().zip(loaded) {
+            let bytes = match bytes {
+                Some(bytes) => bytes.0,
+                None => bail!(
+                    "Module {} not found at block {}",
+                    id,
+                    height.as_deref().unwrap_or("latest")
+                ),
+            };
+            let module = CompiledModule::deserialize(&bytes)
+                .map_err(|err| anyhow!("Failed to deserialize module {}: {}", id, err))?;
+            deps.extend(module.immediate_dependencies());
+            modules.insert(id, bytes);
+        }
+        next = deps;
+    }
+}
+
+/// Loads the account resources from the node.
+/// Missing resources fail the pull if `required`, otherwise they are skipped.
+pub fn pull_resources(
+    net: &dyn Net,
+    address: &AccountAddress,
+    tags: Vec<StructTag>,
+    height: &Option<Block>,
+    required: bool,
+) -> Result<Vec<(StructTag, Vec<u8>)>> {
+    let mut resources = vec![];
+    for tag in tags {
+        match net.get_resource(address, &tag, height)? {
+            Some(bytes) => resources.push((tag, bytes.0)),
+            None if required => bail!(
+                "Resource {} of {} not found at block {}",
+                tag,
+                address.to_hex_literal(),
+                height.as_deref().unwrap_or("latest")
+            ),
+            None => {}
+        }
+    }
+    Ok(resources)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use anyhow::Result;
+    use move_binary_format::file_format::{
+        empty_module, AddressIdentifierIndex, IdentifierIndex, ModuleHandle,
+    };
+    use move_binary_format::CompiledModule;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::{ModuleId, StructTag};
+    use net::{Block, BytesForBlock, Net};
+
+    use super::{pull_modules, pull_resources, StateFile};
+
+    /// Node with the given modules and a single resource.
+    struct ModulesNet(HashMap<ModuleId, CompiledModule>);
+
+    impl Net for ModulesNet {
+        fn get_module(
+            &self,
+            module_id: &ModuleId,
+            _height: &Option<Block>,
+        ) -> Result<Option<BytesForBlock>> {
+            match self.0.get(module_id) {
+                Some(module) => {
+                    let mut bytes = vec![];
+                    module.serialize(&mut bytes)?;
+                    Ok(Some(BytesForBlock(bytes, "1".to_string())))
+                }
+                None => Ok(None),
+            }
+        }
+
+        fn get_resource(
+            &self,
+            _address: &AccountAddress,
+            tag: &StructTag,
+            _height: &Option<Block>,
+        ) -> Result<Option<BytesForBlock>> {
+            Ok((tag.name.as_str() == "U64").then(|| BytesForBlock(vec![1], "1".to_string())))
+        }
+    }
+
+    fn module_id(address: u8, name: &str) -> ModuleId {
+        let mut bytes = [0; AccountAddress::LENGTH];
+        bytes[AccountAddress::LENGTH - 1] = address;
+        ModuleId::new(AccountAddress::new(bytes), Identifier::new(name).unwrap())
+    }
+
+    /// Module that depends on `deps`.
+    fn module(deps: &[ModuleId]) -> CompiledModule {
+        let mut module = empty_module();
+        for dep in deps {
+            module.address_identifiers.push(*dep.address());
+            module.identifiers.push(dep.name().to_owned());
+            module.module_handles.push(ModuleHandle {
+                address: AddressIdentifierIndex(module.address_identifiers.len() as u16 - 1),
+                name: IdentifierIndex(module.identifiers.len() as u16 - 1),
+            });
+        }
+        module
+    }
+
+    #[test]
+    fn test_pull_modules() {
+        // 0x1::Top -> 0x2::Dep -> 0x1::Base, 0x1::Top -> 0x1::Base
+        let net = ModulesNet(
+            vec![
+                (
+                    module_id(1, "Top"),
+                    module(&[module_id(2, "Dep"), module_id(1, "Base")]),
+                ),
+                (module_id(2, "Dep"), module(&[module_id(1, "Base")])),
+                (module_id(1, "Base"), module(&[])),
+                (module_id(1, "Broken"), module(&[module_id(3, "Missing")])),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        let modules =
+            pull_modules(&net, vec![module_id(1, "Top"), module_id(1, "Top")], &None).unwrap();
+        assert_eq!(
+            modules.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                module_id(1, "Base"),
+                module_id(1, "Top"),
+                module_id(2, "Dep")
+            ]
+        );
+
+        let err = pull_modules(&net, vec![module_id(1, "Broken")], &None).unwrap_err();
+        assert!(err.to_string().contains("Missing not found"));
+    }
+
+    #[test]
+    fn test_pull_resources() {
+        let net = ModulesNet(HashMap::new());
+        let tag = |name: &str| StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("Store").unwrap(),
+            name: Identifier::new(name).unwrap(),
+            type_params: vec![],
+        };
+
+        let resources = pull_resources(
+            &net,
+            &AccountAddress::ONE,
+            vec![tag("U64"), tag("U128")],
+            &None,
+            false,
+        )
+        .unwrap();
+        assert_eq!(resources, vec![(tag("U64"), vec![1])]);
+        pull_resources(
+            &net,
+            &AccountAddress::ONE,
+            vec![tag("U64"), tag("U128")],
+            &None,
+            true,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_parse_state_file() {
+        let file: StateFile = serde_json::from_str(
+            r#"{
+                "resources": [
+                    { "account": "Demo", "type": "Demo::Store::U64", "value": { "val": 100 } }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(file.resources.len(), 1);
+        assert_eq!(file.resources[0].tp, "Demo::Store::U64");
+
+        serde_json::from_str::<StateFile>(r#"{ "modules": [] }"#).unwrap_err();
+    }
+}
@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Error, Result};
+use once_cell::sync::OnceCell;
+use move_binary_format::access::{ModuleAccess, ScriptAccess};
+use move_binary_format::file_format::{
+    FunctionHandleIndex, FunctionInstantiationIndex, ModuleHandleIndex,
+};
+use move_binary_format::{CompiledModule, CompiledScript};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::ModuleId;
+use move_core_types::resolver::ModuleResolver;
+
+/// Environment variable with the path to the VM trace file.
+/// The VM reads it once, so tracing must be enabled before the first execution.
+const MOVE_VM_TRACE: &str = "MOVE_VM_TRACE";
+
+/// VM trace file of the process. The VM keeps appending to it once the tracing is on,
+/// so it is set only once.
+static TRACE_FILE: OnceCell<PathBuf> = OnceCell::new();
+
+/// Part of the VM trace file written by the executions after [`enable`].
+pub struct VmTrace {
+    path: PathBuf,
+    start: u64,
+}
+
+/// Enables the VM instruction trace to `path`.
+/// The previous trace is removed when the tracing is enabled for the first time in the process,
+/// later calls must use the same path and read only the new instructions.
+pub fn enable(path: &Path) -> Result<VmTrace> {
+    let enabled = TRACE_FILE.get_or_try_init(|| -> Result<PathBuf> {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        std::env::set_var(MOVE_VM_TRACE, path);
+        Ok(path.to_owned())
+    })?;
+    ensure!(
+        enabled == path,
+        "The VM trace of the process is already written to {:?}",
+        enabled
+    );
+    Ok(VmTrace {
+        path: path.to_owned(),
+        start: fs::metadata(path)
+            .map(|meta| meta.len())
+            .unwrap_or_default(),
+    })
+}
+
+impl VmTrace {
+    /// Instructions executed since the trace was enabled.
+    pub fn entries(&self) -> Result<Vec<TraceEntry>> {
+        parse_trace(&self.content()?)
+    }
+
+    /// Saves the trace to `path`. `script` is the bytecode of the executed script.
+    pub fn save(&self, path: &Path, script: Option<&Path>) -> Result<()> {
+        let mut content = script
+            .map(|script| format!("{}{}\n", SCRIPT_HEADER, script.display()))
+            .unwrap_or_default();
+        content.push_str(&self.content()?);
+        fs::write(path, content)
+            .map_err(|err| anyhow!("Failed to write trace to {:?}: {}", path, err))
+    }
+
+    fn content(&self) -> Result<String> {
+        let mut content = String::new();
+        File::open(&self.path)
+            .and_then(|mut file| {
+                file.seek(SeekFrom::Start(self.start))?;
+                file.read_to_string(&mut content)
+            })
+            .map_err(|err| anyhow!("Failed to read VM trace {:?}: {}", self.path, err))?;
+        // Every execution runs at least one instruction.
+        ensure!(
+            !content.trim().is_empty(),
+            "The VM trace {:?} is empty. The tracing must be enabled before the first execution \
+            in the process",
+            self.path
+        );
+        Ok(content)
+    }
+}
+
+/// Executed instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// Function in the `0xADDRESS::Module::function` format or the script name.
+    pub function: String,
+    /// Bytecode offset in the function.
+    pub pc: u16,
+    /// Instruction in the bytecode debug format, e.g. `LdU64(1)`.
+    pub instruction: String,
+}
+
+impl TraceEntry {
+    /// Instruction name without operands, e.g. `LdU64`.
+    pub fn instruction_name(&self) -> &str {
+        self.instruction
+            .split('(')
+            .next()
+            .unwrap_or(&self.instruction)
+    }
+
+    /// Module of the function. `None` for scripts.
+    pub fn module_id(&self) -> Option<ModuleId> {
+        let (module, _) = split_function(&self.function)?;
+        Some(module)
+    }
+
+    /// Function name.
+    pub fn function_name(&self) -> &str {
+        self.function.rsplit("::").next().unwrap_or(&self.function)
+    }
+
+    /// Index of the called function if the instruction is a call.
+    pub fn call(&self) -> Option<CallIndex> {
+        let operand = |prefix: &str| {
+            self.instruction
+                .strip_prefix(prefix)?
+                .trim_end_matches(')')
+                .parse::<u16>()
+                .ok()
+        };
+        operand("Call(FunctionHandleIndex(")
+            .map(|idx| CallIndex::Handle(FunctionHandleIndex(idx)))
+            .or_else(|| {
+                operand("CallGeneric(FunctionInstantiationIndex(")
+                    .map(|idx| CallIndex::Instantiation(FunctionInstantiationIndex(idx)))
+            })
+    }
+
+    /// Returns `true` if the instruction returns from the function.
+    pub fn is_return(&self) -> bool {
+        self.instruction == "Ret"
+    }
+}
+
+/// Operand of the call instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallIndex {
+    Handle(FunctionHandleIndex),
+    Instantiation(FunctionInstantiationIndex),
+}
+
+/// Header of the saved trace with the path to the executed script bytecode.
+const SCRIPT_HEADER: &str = "# script: ";
+
+/// Parses the trace file. Header lines starting with `#` are skipped.
+pub fn read_trace(path: &Path) -> Result<Vec<TraceEntry>> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| anyhow!("Failed to read VM trace {:?}: {}", path, err))?;
+    parse_trace(&content)
+}
+
+fn parse_trace(content: &str) -> Result<Vec<TraceEntry>> {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(parse_line)
+        .collect()
+}
+
+/// Returns the path to the executed script bytecode saved in the trace header.
+pub fn trace_script(path: &Path) -> Result<Option<PathBuf>> {
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .take_while(|line| line.starts_with('#'))
+        .find_map(|line| line.strip_prefix(SCRIPT_HEADER))
+        .map(PathBuf::from))
+}
+
+fn parse_line(line: &str) -> Result<TraceEntry, Error> {
+    let mut parts = line.splitn(3, ',');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(function), Some(pc), Some(instruction)) => Ok(TraceEntry {
+            function: function.to_string(),
+            pc: pc
+                .parse()
+                .map_err(|err| anyhow!("Invalid trace line '{}': {}", line, err))?,
+            instruction: instruction.to_string(),
+        }),
+        _ => bail!("Invalid trace line '{}'", line),
+    }
+}
+
+fn split_function(function: &str) -> Option<(ModuleId, &str)> {
+    let mut parts = function.split("::");
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(address), Some(module), Some(name), None) => {
+            let address = AccountAddress::from_hex_literal(&format!(
+                "0x{}",
+                address.trim_start_matches("0x")
+            ))
+            .ok()?;
+            Some((ModuleId::new(address, Identifier::new(module).ok()?), name))
+        }
+        _ => None,
+    }
+}
+
+/// Resolves call targets of the traced instructions.
+pub struct CallResolver<'a> {
+    modules: &'a dyn ModuleResolver<Error = Error>,
+    script: Option<CompiledScript>,
+    cache: HashMap<ModuleId, Option<CompiledModule>>,
+}
+
+impl<'a> CallResolver<'a> {
+    /// `script` is the executed script if the transaction is a script.
+    pub fn new(
+        modules: &'a dyn ModuleResolver<Error = Error>,
+        script: Option<CompiledScript>,
+    ) -> CallResolver<'a> {
+        CallResolver {
+            modules,
+            script,
+            cache: Default::default(),
+        }
+    }
+
+    /// Returns the module and the name of the function called by the instruction.
+    pub fn callee(&mut self, entry: &TraceEntry) -> Option<(ModuleId, Identifier)> {
+        let call = entry.call()?;
+        match entry.module_id() {
+            Some(module_id) => {
+                let module = self.module(&module_id)?;
+                let handle = match call {
+                    CallIndex::Handle(idx) => module.function_handle_at(idx),
+                    CallIndex::Instantiation(idx) => {
+                        module.function_handle_at(module.function_instantiation_at(idx).handle)
+                    }
+                };
+                Some((
+                    module_id_for_handle(module, handle.module),
+                    module.identifier_at(handle.name).to_owned(),
+                ))
+            }
+            None => {
+                let script = self.script.as_ref()?;
+                let handle = match call {
+                    CallIndex::Handle(idx) => script.function_handle_at(idx),
+                    CallIndex::Instantiation(idx) => {
+                        script.function_handle_at(script.function_instantiation_at(idx).handle)
+                    }
+                };
+                let module = script.module_handle_at(handle.module);
+                Some((
+                    ModuleId::new(
+                        *script.address_identifier_at(module.address),
+                        script.identifier_at(module.name).to_owned(),
+                    ),
+                    script.identifier_at(handle.name).to_owned(),
+                ))
+            }
+        }
+    }
+
+    fn module(&mut self, module_id: &ModuleId) -> Option<&CompiledModule> {
+        let modules = self.modules;
+        self.cache
+            .entry(module_id.clone())
+            .or_insert_with(|| {
+                modules
+                    .get_module(module_id)
+                    .ok()
+                    .flatten()
+                    .and_then(|bytes| CompiledModule::deserialize(&bytes).ok())
+            })
+            .as_ref()
+    }
+}
+
+fn module_id_for_handle(module: &CompiledModule, idx: ModuleHandleIndex) -> ModuleId {
+    module.module_id_for_handle(module.module_handle_at(idx))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{parse_line, read_trace, CallIndex, VmTrace};
+    use move_binary_format::file_format::{FunctionHandleIndex, FunctionInstantiationIndex};
+
+    #[test]
+    fn test_parse_line() {
+        let entry = parse_line(
+            "0x00000000000000000000000000000001::Store::get,3,VecPack(SignatureIndex(1), 2)",
+        )
+        .unwrap();
+        assert_eq!(entry.pc, 3);
+        assert_eq!(entry.instruction_name(), "VecPack");
+        assert_eq!(entry.function_name(), "get");
+        assert_eq!(entry.module_id().unwrap().name().as_str(), "Store");
+        assert_eq!(entry.call(), None);
+
+        let entry = parse_line("Script::main,0,Call(FunctionHandleIndex(2))").unwrap();
+        assert_eq!(entry.module_id(), None);
+        assert_eq!(
+            entry.call(),
+            Some(CallIndex::Handle(FunctionHandleIndex(2)))
+        );
+
+        let entry =
+            parse_line("Script::main,1,CallGeneric(FunctionInstantiationIndex(0))").unwrap();
+        assert_eq!(
+            entry.call(),
+            Some(CallIndex::Instantiation(FunctionInstantiationIndex(0)))
+        );
+        assert!(parse_line("main,x,Ret").is_err());
+    }
+
+    #[test]
+    fn test_vm_trace_since_start() {
+        let dir = std::env::temp_dir().join("dove_vm_trace_test");
+        fs::create_dir_all(&dir).unwrap();
+        let vm_trace = dir.join("vm_trace");
+        let first = "Script::main,0,Ret\n";
+        fs::write(&vm_trace, first).unwrap();
+
+        // Nothing is executed after the trace is enabled.
+        let trace = VmTrace {
+            path: vm_trace.clone(),
+            start: first.len() as u64,
+        };
+        assert!(trace.entries().is_err());
+
+        // The previous executions are not included.
+        fs::write(
+            &vm_trace,
+            format!("{}Script::main,0,LdU64(1)\nScript::main,1,Pop\n", first),
+        )
+        .unwrap();
+        let entries = trace.entries().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].instruction, "LdU64(1)");
+
+        let path = dir.join("trace.txt");
+        trace.save(&path, None).unwrap();
+        assert_eq!(read_trace(&path).unwrap(), entries);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,48 @@
+mod helpers;
+
+use std::fs;
+use helpers::{new_demo_project, dove, delete_project};
+
+/// $ dove fuzz one_param --runs 10 --seed 1
+/// $ dove fuzz one_param --runs 10 --seed 1 --allow-abort 2
+#[test]
+fn test_cmd_dove_fuzz() {
+    let project_name = "project_fuzz";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let err = dove(
+        &["fuzz", "one_param", "--runs", "10", "--seed", "1"],
+        &project_folder,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("unexpected abort with code 2"));
+    assert!(err.contains("replay: dove run 'one_param(false)'"));
+    let call = fs::read_to_string(
+        project_folder
+            .join("build")
+            .join("fuzz")
+            .join("one_param")
+            .join("failure_0.call"),
+    )
+    .unwrap();
+    assert_eq!(call.trim(), "one_param(false)");
+
+    let output = dove(
+        &[
+            "fuzz",
+            "one_param",
+            "--runs",
+            "10",
+            "--seed",
+            "1",
+            "--allow-abort",
+            "2",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("10 runs, no failures found"));
+
+    delete_project(&project_folder).unwrap();
+}
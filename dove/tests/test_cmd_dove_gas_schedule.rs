@@ -0,0 +1,52 @@
+mod helpers;
+
+use std::fs;
+use helpers::{new_demo_project, dove, delete_project};
+
+/// $ dove gas-schedule dump --output gas.toml
+/// $ dove gas-schedule check gas.toml
+/// $ dove run 'Store::store_u64(Demo, 100)' --gas-schedule gas.toml
+#[test]
+fn test_cmd_dove_gas_schedule() {
+    let project_name = "project_gas_schedule";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    dove(
+        &["gas-schedule", "dump", "--output", "gas.toml"],
+        &project_folder,
+    )
+    .unwrap();
+    let output = dove(&["gas-schedule", "check", "gas.toml"], &project_folder).unwrap();
+    assert!(output.contains("is valid"));
+    dove(
+        &[
+            "run",
+            "Store::store_u64(Demo, 100)",
+            "--gas-schedule",
+            "gas.toml",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+
+    // The table referenced from Move.toml is validated.
+    let gas = fs::read_to_string(project_folder.join("gas.toml")).unwrap();
+    fs::write(
+        project_folder.join("gas.toml"),
+        gas.replace("[natives.SHA2_256]", "[natives.SHA2_512]"),
+    )
+    .unwrap();
+    let manifest = fs::read_to_string(project_folder.join("Move.toml")).unwrap();
+    fs::write(
+        project_folder.join("Move.toml"),
+        manifest.replace("[package]", "[package]\ngas_schedule = \"gas.toml\""),
+    )
+    .unwrap();
+    let err = dove(&["run", "Store::store_u64(Demo, 100)"], &project_folder)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("missing natives: SHA2_256"));
+    assert!(err.contains("unknown natives: SHA2_512"));
+
+    delete_project(&project_folder).unwrap();
+}
@@ -0,0 +1,45 @@
+mod helpers;
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use helpers::{new_demo_project, delete_project};
+
+/// $ dove repl --signer Demo
+#[test]
+fn test_cmd_dove_repl() {
+    let project_name = "project_repl";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_dove"))
+        .current_dir(&project_folder)
+        .env("MOVE_HOME", project_folder.join(".move"))
+        .args(&["repl", "--signer", "Demo"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(
+            b"Store::store_u64(100)\n\
+            Store::get_u64(Demo)\n\
+            :view Demo::Store::U64\n\
+            :signer -\n\
+            :history 1\n\
+            :quit\n",
+        )
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let output = String::from_utf8(output.stdout).unwrap();
+    assert!(output.contains("Executed."));
+    assert!(output.contains("#0: u64 = 100"));
+    assert!(output.contains("Signer is not set"));
+    assert!(output.contains("    :signer -"));
+    assert!(project_folder.join(".move").join("repl_history").exists());
+
+    delete_project(&project_folder).unwrap();
+}
@@ -32,6 +32,27 @@ fn test_cmd_dove_run_with_params() {
     delete_project(&project_folder).unwrap();
 }
 
+/// $ dove run 'Store::store_u64(Demo, 100)' -v
+#[test]
+fn test_cmd_dove_run_verbose() {
+    let project_name = "project_run_verbose";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let output = dove(
+        &["run", "Store::store_u64(Demo, 100)", "-v"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("Write set:"));
+    assert!(output.contains("Store::U64"));
+    assert!(output.contains("100"));
+
+    let output = dove(&["run", "Store::store_u64(Demo, 100)"], &project_folder).unwrap();
+    assert!(!output.contains("Write set:"));
+
+    delete_project(&project_folder).unwrap();
+}
+
 /// With type
 /// $ dove run 'with_type<u8>(1)'
 /// $ dove run 'with_type(1)' -t u8
@@ -67,3 +88,238 @@ fn test_cmd_dove_run_multiple() {
 
     delete_project(&project_folder).unwrap();
 }
+
+/// Return values of public functions
+/// $ dove run 'Store::pair(7, true)'
+/// $ dove run 'Store::get_u64(Demo)' --json
+#[test]
+fn test_cmd_dove_run_return_values() {
+    let project_name = "project_run_return_values";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let output = dove(&["run", "Store::pair(7, true)"], &project_folder).unwrap();
+    assert!(output.contains("#0: u64 = 7"));
+    assert!(output.contains("#1: bool = true"));
+
+    dove(&["run", "Store::store_u64(Demo, 100)"], &project_folder).unwrap();
+    let output = dove(&["run", "Store::get_u64(Demo)", "--json"], &project_folder).unwrap();
+    assert!(output.contains("\"type\": \"u64\""));
+    assert!(output.contains("100"));
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// Execution report
+/// $ dove run 'Store::store_u64(Demo, 100)' --report json --dry-run
+#[test]
+fn test_cmd_dove_run_report() {
+    let project_name = "project_run_report";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let call = [
+        "run",
+        "Store::store_u64(Demo, 100)",
+        "--report",
+        "json",
+        "--dry-run",
+    ];
+    let output = dove(&call, &project_folder).unwrap();
+    assert!(output.contains("\"status\": \"executed\""));
+    assert!(output.contains("\"change\": \"published\""));
+    assert!(output.contains("Store::U64"));
+    assert_eq!(output, dove(&call, &project_folder).unwrap());
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// $ dove run 'Store::store_u64(Demo, 100)' --gas-profile --folded gas.folded
+#[test]
+#[cfg(feature = "vm-trace")]
+fn test_cmd_dove_run_gas_profile() {
+    let project_name = "project_run_gas_profile";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let output = dove(
+        &[
+            "run",
+            "Store::store_u64(Demo, 100)",
+            "--gas-profile",
+            "--folded",
+            "gas.folded",
+            "--dry-run",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("Gas used: "));
+    assert!(output.contains("Functions (estimated):"));
+    assert!(output.contains("::Store::store_u64"));
+    assert!(output.contains("Instructions (estimated):"));
+    assert!(output.contains("MoveTo"));
+
+    let folded = std::fs::read_to_string(project_folder.join("gas.folded")).unwrap();
+    assert!(folded.lines().all(|line| {
+        line.starts_with("estimated gas;")
+            && line.rsplit(' ').next().unwrap().parse::<u64>().is_ok()
+    }));
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// $ dove run 'Store::store_u64(Demo, 100)' --trace trace.txt
+/// $ dove trace view trace.txt
+#[test]
+#[cfg(feature = "vm-trace")]
+fn test_cmd_dove_run_trace() {
+    let project_name = "project_run_trace";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    dove(
+        &[
+            "run",
+            "Store::store_u64(Demo, 100)",
+            "--trace",
+            "trace.txt",
+            "--dry-run",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    let output = dove(&["trace", "view", "trace.txt"], &project_folder).unwrap();
+    assert!(output.contains("::Store::store_u64+0"));
+    assert!(output.contains("sources/store.move:"));
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// $ dove run 'one_param(false)'
+#[test]
+fn test_cmd_dove_run_abort_diagnostic() {
+    let project_name = "project_run_abort_diagnostic";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let output = dove(&["run", "one_param(false)"], &project_folder).unwrap();
+    assert!(output.contains("execution aborted with code 2"));
+    assert!(output.contains("scripts/one_param.move:3"));
+    assert!(output.contains("assert!(a, 2);"));
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// $ dove run 'Store::get_u64(Demo)' --fork file://PATH
+/// $ dove run 'Store::store_u64(0x3, 5)' --fork file://PATH
+#[test]
+fn test_cmd_dove_run_fork() {
+    let project_name = "project_run_fork";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    // A named sandbox plays the role of the chain state.
+    dove(
+        &["run", "Store::store_u64(Demo, 100)", "--sandbox", "chain"],
+        &project_folder,
+    )
+    .unwrap();
+    let chain = project_folder
+        .join("sandboxes")
+        .join("chain")
+        .canonicalize()
+        .unwrap();
+    let url = format!("file://{}", chain.display());
+
+    let output = dove(
+        &["run", "Store::get_u64(Demo)", "--fork", &url],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("#0: u64 = 100"));
+    assert!(output.contains("Nothing is submitted."));
+
+    // The sandbox storage keeps only the latest state.
+    let err = dove(
+        &[
+            "run",
+            "Store::get_u64(Demo)",
+            "--fork",
+            &url,
+            "--height",
+            "1000",
+        ],
+        &project_folder,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("Remove --height"));
+
+    let output = dove(
+        &["run", "Store::store_u64(0x3, 5)", "--fork", &url],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("Write set:"));
+    assert!(output.contains("published 0x3"));
+    assert!(output.contains("Gas used:"));
+
+    // The forked state is not changed.
+    dove(
+        &["run", "Store::store_u64(0x3, 5)", "--sandbox", "chain"],
+        &project_folder,
+    )
+    .unwrap();
+    let output = dove(
+        &["run", "Store::get_u64(0x3)", "--sandbox", "chain"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("#0: u64 = 5"));
+
+    delete_project(&project_folder).unwrap();
+}
+
+#[test]
+fn test_cmd_dove_run_fork_record() {
+    let project_name = "project_run_fork_record";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    dove(
+        &["run", "Store::store_u64(Demo, 100)", "--sandbox", "chain"],
+        &project_folder,
+    )
+    .unwrap();
+    let chain = project_folder
+        .join("sandboxes")
+        .join("chain")
+        .canonicalize()
+        .unwrap();
+    let fixture = project_folder.join("fork.json");
+
+    let output = dove(
+        &[
+            "run",
+            "Store::get_u64(Demo)",
+            "--fork",
+            &format!("file://{}", chain.display()),
+            "--record",
+            fixture.to_str().unwrap(),
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("#0: u64 = 100"));
+    assert!(fixture.exists());
+
+    // The recorded responses are served without the chain state.
+    std::fs::remove_dir_all(&chain).unwrap();
+    let output = dove(
+        &[
+            "run",
+            "Store::get_u64(Demo)",
+            "--fork",
+            &format!("file://{}", fixture.canonicalize().unwrap().display()),
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("#0: u64 = 100"));
+
+    delete_project(&project_folder).unwrap();
+}
@@ -0,0 +1,74 @@
+mod helpers;
+
+use std::fs;
+use helpers::{new_demo_project, dove, delete_project};
+
+/// $ dove run 'Store::store_u64(Demo, 100)' --sandbox sim
+/// $ dove sandbox snapshot prepared --sandbox sim
+/// $ dove sandbox diff prepared --sandbox sim
+/// $ dove sandbox restore prepared --sandbox sim
+/// $ dove sandbox list
+#[test]
+fn test_cmd_dove_sandbox() {
+    let project_name = "project_sandbox";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    dove(
+        &["run", "Store::store_u64(Demo, 100)", "--sandbox", "sim"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(project_folder.join("sandboxes").join("sim").exists());
+    dove(
+        &["sandbox", "snapshot", "prepared", "--sandbox", "sim"],
+        &project_folder,
+    )
+    .unwrap();
+
+    fs::write(
+        project_folder.join("fixtures.json"),
+        r#"{
+            "resources": [
+                { "account": "Demo", "type": "Demo::Store::U64", "value": { "val": 7 } }
+            ]
+        }"#,
+    )
+    .unwrap();
+    dove(
+        &["state", "seed", "fixtures.json", "--sandbox", "sim"],
+        &project_folder,
+    )
+    .unwrap();
+    let output = dove(
+        &["sandbox", "diff", "prepared", "--sandbox", "sim"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("~ 0x2 resource"));
+    assert!(output.contains("[prepared -> sim]"));
+    assert!(output.contains("  ~ val: "));
+
+    dove(
+        &["sandbox", "restore", "prepared", "--sandbox", "sim"],
+        &project_folder,
+    )
+    .unwrap();
+    let output = dove(
+        &["run", "Store::get_u64(Demo)", "--sandbox", "sim"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("#0: u64 = 100"));
+    let output = dove(
+        &["sandbox", "diff", "prepared", "--sandbox", "sim"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("No changes"));
+
+    let output = dove(&["sandbox", "list"], &project_folder).unwrap();
+    assert!(output.contains("    sim"));
+    assert!(output.contains("    prepared"));
+
+    delete_project(&project_folder).unwrap();
+}
@@ -0,0 +1,69 @@
+mod helpers;
+
+use std::fs;
+use helpers::{new_demo_project, dove, delete_project};
+
+/// $ dove scenario run store.toml --junit report.xml --json report.json
+#[test]
+fn test_cmd_dove_scenario_run() {
+    let project_name = "project_scenario_run";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    fs::write(
+        project_folder.join("store.toml"),
+        r#"
+        [[step]]
+        name = "store"
+        call = "Store::store_u64(Demo, 100)"
+
+        [[step.expect.resources]]
+        address = "Demo"
+        type = "Demo::Store::U64"
+        fields = { val = 100 }
+
+        [[step]]
+        name = "store twice"
+        call = "Store::store_u64(Demo, 200)"
+        expect = { success = false }
+
+        [[step]]
+        name = "abort"
+        call = "one_param(false)"
+        expect = { abort_code = 2 }
+        "#,
+    )
+    .unwrap();
+
+    let output = dove(
+        &[
+            "scenario",
+            "run",
+            "store.toml",
+            "--junit",
+            "report.xml",
+            "--json",
+            "report.json",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("Scenario store: 3 passed, 0 failed"));
+
+    let junit = fs::read_to_string(project_folder.join("report.xml")).unwrap();
+    assert!(junit.contains("tests=\"3\" failures=\"0\""));
+    let json = fs::read_to_string(project_folder.join("report.json")).unwrap();
+    assert!(json.contains("\"abort_code\": 2"));
+
+    // A failed expectation fails the command.
+    fs::write(
+        project_folder.join("fail.toml"),
+        r#"
+        [[step]]
+        call = "one_param(false)"
+        "#,
+    )
+    .unwrap();
+    assert!(dove(&["scenario", "run", "fail.toml"], &project_folder).is_err());
+
+    delete_project(&project_folder).unwrap();
+}
@@ -0,0 +1,178 @@
+mod helpers;
+
+use std::fs;
+use helpers::{new_demo_project, dove, delete_project};
+
+/// $ dove state seed fixtures.json
+/// $ dove run 'Store::get_u64(Demo)' --state fixtures.json
+#[test]
+fn test_cmd_dove_state_seed() {
+    let project_name = "project_state_seed";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let fixtures = project_folder.join("fixtures.json");
+    fs::write(
+        &fixtures,
+        r#"{
+            "resources": [
+                { "account": "Demo", "type": "Demo::Store::U64", "value": { "val": 42 } }
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let output = dove(&["state", "seed", "fixtures.json"], &project_folder).unwrap();
+    assert!(output.contains("1 resources are written to the sandbox storage"));
+    let output = dove(&["run", "Store::get_u64(Demo)"], &project_folder).unwrap();
+    assert!(output.contains("#0: u64 = 42"));
+
+    fs::write(
+        &fixtures,
+        r#"{
+            "resources": [
+                { "account": "Demo", "type": "Demo::Store::U64", "value": { "val": 7 } }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let output = dove(
+        &["run", "Store::get_u64(Demo)", "--state", "fixtures.json"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("#0: u64 = 7"));
+
+    fs::write(
+        &fixtures,
+        r#"{
+            "resources": [
+                { "account": "Demo", "type": "Demo::Store::U64", "value": { "value": 7 } }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let err = dove(&["state", "seed", "fixtures.json"], &project_folder)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("$.resources[0].value.value: unknown field"));
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// $ dove state pull Demo --resources Demo::Store::U64 --url file://<chain>
+/// $ dove state pull 0x1 --modules --url file://<chain>
+#[test]
+fn test_cmd_dove_state_pull() {
+    let project_name = "project_state_pull";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    // A named sandbox plays the role of the chain state.
+    dove(
+        &["run", "Store::store_u64(Demo, 100)", "--sandbox", "chain"],
+        &project_folder,
+    )
+    .unwrap();
+    let chain = project_folder
+        .join("sandboxes")
+        .join("chain")
+        .canonicalize()
+        .unwrap();
+    let url = format!("file://{}", chain.display());
+
+    let output = dove(
+        &[
+            "state",
+            "pull",
+            "Demo",
+            "--resources",
+            "Demo::Store::U64",
+            "--url",
+            &url,
+            "--sandbox",
+            "pulled",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("0 modules and 1 resources are pulled"));
+    let output = dove(
+        &["run", "Store::get_u64(Demo)", "--sandbox", "pulled"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("#0: u64 = 100"));
+
+    let output = dove(
+        &[
+            "state",
+            "pull",
+            "0x1",
+            "--modules",
+            "--url",
+            &url,
+            "--sandbox",
+            "pulled",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("module 0x1::"));
+    assert!(project_folder
+        .join("sandboxes")
+        .join("pulled")
+        .join("0x1")
+        .join("modules")
+        .exists());
+
+    let err = dove(
+        &[
+            "state",
+            "pull",
+            "0x1",
+            "--modules",
+            "Missing",
+            "--url",
+            &url,
+        ],
+        &project_folder,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("not found"));
+
+    // Without flags only the modules are pulled.
+    let output = dove(
+        &[
+            "state",
+            "pull",
+            "0x1",
+            "--url",
+            &url,
+            "--sandbox",
+            "default",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("module 0x1::"));
+    assert!(output.contains("and 0 resources are pulled"));
+
+    // Resources listed by the storage.
+    let output = dove(
+        &[
+            "state",
+            "pull",
+            "Demo",
+            "--resources",
+            "--url",
+            &url,
+            "--sandbox",
+            "listed",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("0 modules and 1 resources are pulled"));
+
+    delete_project(&project_folder).unwrap();
+}
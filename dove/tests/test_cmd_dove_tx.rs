@@ -0,0 +1,140 @@
+mod helpers;
+
+use std::fs;
+use serde_json::Value;
+use helpers::{new_demo_project, dove, delete_project};
+
+const GENESIS_HASH: &str = "0x3fd7b9eb6a00376e5be61f01abb429ffb0b104be05eaff4d458da48fcd425baf";
+
+/// $ dove call 'two_params(1,2)'
+/// $ dove tx inspect two_params
+/// $ dove tx inspect build/for_tests/transaction/with_type.mvt --json
+#[test]
+fn test_cmd_dove_tx_inspect() {
+    let project_name = "project_tx_inspect";
+    let project_folder = new_demo_project(project_name).unwrap();
+    dove(&["call", "two_params(1,2)"], &project_folder).unwrap();
+    dove(&["call", "with_type<u8>(7)"], &project_folder).unwrap();
+
+    let output = dove(&["tx", "inspect", "two_params"], &project_folder).unwrap();
+    assert!(output.contains("Script: two_params"));
+    assert!(output.contains("Type arguments: []"));
+    assert!(output.contains("a: u8 = 1"));
+    assert!(output.contains("b: u8 = 2"));
+
+    let path = project_folder
+        .join("build")
+        .join("for_tests")
+        .join("transaction")
+        .join("with_type.mvt");
+    let output = dove(
+        &["tx", "inspect", path.to_str().unwrap(), "--json"],
+        &project_folder,
+    )
+    .unwrap();
+    let info: Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(info["call"]["script"]["name"], "with_type");
+    assert_eq!(info["type_args"][0], "u8");
+    assert_eq!(info["args"][0]["type"], "u8");
+    assert_eq!(info["args"][0]["value"], 7);
+
+    let err = dove(&["tx", "inspect", "missing"], &project_folder)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("not found"));
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// $ dove tx sign main --account alice --gas 100 ...
+/// Signing parameters are validated before the transaction is signed.
+#[test]
+fn test_cmd_dove_tx_sign_params() {
+    let project_name = "project_tx_sign_params";
+    let project_folder = new_demo_project(project_name).unwrap();
+    dove(&["call", "main()"], &project_folder).unwrap();
+
+    let sign = |params: &[&str]| {
+        let mut args = vec!["tx", "sign", "main", "--account", "alice"];
+        args.extend_from_slice(params);
+        dove(&args, &project_folder).unwrap_err().to_string()
+    };
+
+    let err = sign(&[
+        "--genesis-hash",
+        GENESIS_HASH,
+        "--spec-version",
+        "1",
+        "--tx-version",
+        "1",
+    ]);
+    assert!(err.contains("Please specify gas limit"));
+
+    let err = sign(&[
+        "--gas",
+        "100",
+        "--genesis-hash",
+        GENESIS_HASH,
+        "--spec-version",
+        "1",
+        "--tx-version",
+        "1",
+    ]);
+    assert!(err.contains("Please specify nonce"));
+
+    let err = sign(&[
+        "--gas",
+        "100",
+        "--nonce",
+        "0",
+        "--genesis-hash",
+        "0x3fd7",
+        "--spec-version",
+        "1",
+        "--tx-version",
+        "1",
+    ]);
+    assert!(err.contains("Genesis hash must be 32 bytes"));
+
+    // Flags are completed from the metadata file.
+    fs::write(
+        project_folder.join("chain.json"),
+        r#"{"nonce": 0, "genesis_hash": "0x3fd7", "spec_version": 1}"#,
+    )
+    .unwrap();
+    let err = sign(&["--gas", "100", "--meta", "chain.json"]);
+    assert!(err.contains("Please specify transaction version"));
+    let err = sign(&["--gas", "100", "--meta", "chain.json", "--tx-version", "1"]);
+    assert!(err.contains("Genesis hash must be 32 bytes"));
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// $ dove tx submit main.signed
+/// The extrinsic file is validated before connecting to the node.
+#[test]
+fn test_cmd_dove_tx_submit_params() {
+    let project_name = "project_tx_submit_params";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    let err = dove(&["tx", "submit", "missing.signed"], &project_folder)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Failed to read"));
+
+    fs::write(project_folder.join("main.signed"), "not an extrinsic").unwrap();
+    let err = dove(&["tx", "submit", "main.signed"], &project_folder)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("does not contain a signed extrinsic"));
+
+    let err = dove(
+        &["tx", "submit", "main.signed", "--url", "not a url"],
+        &project_folder,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("--url"));
+
+    delete_project(&project_folder).unwrap();
+}
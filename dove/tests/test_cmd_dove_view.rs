@@ -0,0 +1,124 @@
+mod helpers;
+
+use std::fs;
+use serde_json::{json, Value};
+use helpers::{new_demo_project, dove, delete_project};
+
+/// $ dove run 'Store::store_u64(Demo, 100)'
+/// $ dove view Demo::Store::U64 --local
+/// $ dove view Demo --local
+#[test]
+fn test_cmd_dove_view_local() {
+    let project_name = "project_view_local";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    dove(&["run", "Store::store_u64(Demo, 100)"], &project_folder).unwrap();
+
+    let output = dove(&["view", "Demo::Store::U64", "--local"], &project_folder).unwrap();
+    assert!(output.contains("100"));
+
+    let output = dove(
+        &["view", "Demo::Store::U64", "--local", "--json"],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("\"height\": \"local\""));
+
+    let output = dove(&["view", "Demo", "--local"], &project_folder).unwrap();
+    assert!(output.contains("Store::U64"));
+    assert!(output.contains("100"));
+
+    let err = dove(&["view", "u64", "--local"], &project_folder)
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Expected account or resource type. Actual: u64"));
+
+    delete_project(&project_folder).unwrap();
+}
+
+/// $ dove view Demo::Store::U64 --local --record fixture.json
+/// $ dove view Demo::Store::U64 --api file://fixture.json --diff-from 100 --diff-to 105
+#[test]
+fn test_cmd_dove_view_diff() {
+    let project_name = "project_view_diff";
+    let project_folder = new_demo_project(project_name).unwrap();
+
+    dove(&["run", "Store::store_u64(Demo, 100)"], &project_folder).unwrap();
+    dove(
+        &[
+            "view",
+            "Demo::Store::U64",
+            "--local",
+            "--record",
+            "fixture.json",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+
+    // The recorded latest state is replayed as blocks 100 and 105 with different values.
+    let fixture_path = project_folder.join("fixture.json");
+    let recorded: Value =
+        serde_json::from_str(&fs::read_to_string(&fixture_path).unwrap()).unwrap();
+    let at_height = |entry: &Value, height: &str| {
+        let mut entry = entry.clone();
+        entry["height"] = json!(height);
+        entry["block"] = json!(height);
+        entry
+    };
+    let modules = recorded["modules"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|entry| vec![at_height(entry, "100"), at_height(entry, "105")])
+        .collect::<Vec<_>>();
+    let resource = &recorded["resources"][0];
+    assert_eq!(resource["bytes"], json!("0x6400000000000000"));
+    let mut changed = at_height(resource, "105");
+    changed["bytes"] = json!("0x6900000000000000");
+    let fixture = json!({
+        "modules": modules,
+        "resources": [at_height(resource, "100"), changed],
+    });
+    fs::write(&fixture_path, fixture.to_string()).unwrap();
+
+    let api = format!("file://{}", fixture_path.display());
+    let output = dove(
+        &[
+            "view",
+            "Demo::Store::U64",
+            "--api",
+            &api,
+            "--diff-from",
+            "100",
+            "--diff-to",
+            "105",
+        ],
+        &project_folder,
+    )
+    .unwrap();
+    assert!(output.contains("[100 -> 105]"));
+    assert!(output.contains("~"));
+    assert!(!output.contains("No changes"));
+
+    // The sandbox storage keeps only the latest state.
+    let api = format!("file://{}", project_folder.join("storage").display());
+    let err = dove(
+        &[
+            "view",
+            "Demo::Store::U64",
+            "--api",
+            &api,
+            "--diff-from",
+            "100",
+            "--diff-to",
+            "105",
+        ],
+        &project_folder,
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(err.contains("Diff between blocks is not available"));
+
+    delete_project(&project_folder).unwrap();
+}
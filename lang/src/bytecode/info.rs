@@ -1,10 +1,12 @@
+use std::fmt::{Display, Formatter};
 use anyhow::Error;
 use move_binary_format::access::{ModuleAccess, ScriptAccess};
 use move_binary_format::CompiledModule;
 use move_binary_format::file_format::{
-    Ability, AbilitySet, SignatureToken, StructHandleIndex, Visibility,
+    Ability, AbilitySet, SignatureToken, StructFieldInformation, StructHandleIndex, Visibility,
 };
 use move_core_types::account_address::AccountAddress;
+use move_core_types::language_storage::TypeTag;
 use crate::bytecode::accessor::{Bytecode, BytecodeRef};
 
 #[derive(Debug)]
@@ -55,6 +57,25 @@ impl BytecodeInfo {
     }
 
     pub fn find_script_function(&self, need_name: &str) -> Option<Script> {
+        self.find_function(need_name, |visibility| visibility == Visibility::Script)
+    }
+
+    /// Finds a function with script or public visibility.
+    pub fn find_public_function(&self, need_name: &str) -> Option<Script> {
+        self.find_function(need_name, |visibility| {
+            visibility == Visibility::Script || visibility == Visibility::Public
+        })
+    }
+
+    /// Finds a function with any visibility.
+    pub fn find_any_function(&self, need_name: &str) -> Option<Script> {
+        self.find_function(need_name, |_| true)
+    }
+
+    fn find_function<F>(&self, need_name: &str, visible: F) -> Option<Script>
+    where
+        F: Fn(Visibility) -> bool,
+    {
         match &self.bytecode {
             Bytecode::Script(name, script, module, _) => {
                 if name != need_name {
@@ -76,6 +97,7 @@ impl BytecodeInfo {
 
                 Some(Script {
                     name: name.to_string(),
+                    index: 0,
                     parameters,
                     type_parameters,
                     returns: vec![],
@@ -84,12 +106,13 @@ impl BytecodeInfo {
             Bytecode::Module(module, _) => module
                 .function_defs()
                 .iter()
-                .filter(|def| def.visibility == Visibility::Script)
-                .find(|def| {
+                .enumerate()
+                .filter(|(_, def)| visible(def.visibility))
+                .find(|(_, def)| {
                     let handle = module.function_handle_at(def.function);
                     module.identifier_at(handle.name).as_str() == need_name
                 })
-                .map(|def| {
+                .map(|(index, def)| {
                     let handle = module.function_handle_at(def.function);
                     let parameters = module
                         .signature_at(handle.parameters)
@@ -107,6 +130,7 @@ impl BytecodeInfo {
 
                     Script {
                         name: module.identifier_at(handle.name).to_string(),
+                        index: index as u16,
                         parameters,
                         type_parameters,
                         returns: return_.iter().map(|st| make_type(st, module)).collect(),
@@ -114,11 +138,67 @@ impl BytecodeInfo {
                 }),
         }
     }
+
+    /// Returns names of the non-generic structs with the `key` ability.
+    pub fn resource_names(&self) -> Vec<String> {
+        match &self.bytecode {
+            Bytecode::Script(_, _, _, _) => vec![],
+            Bytecode::Module(module, _) => module
+                .struct_defs()
+                .iter()
+                .map(|def| module.struct_handle_at(def.struct_handle))
+                .filter(|handle| {
+                    handle.abilities.has_ability(Ability::Key)
+                        && handle.type_parameters.is_empty()
+                })
+                .map(|handle| module.identifier_at(handle.name).to_string())
+                .collect(),
+        }
+    }
+
+    pub fn find_struct(&self, need_name: &str) -> Option<Struct> {
+        match &self.bytecode {
+            Bytecode::Script(_, _, _, _) => None,
+            Bytecode::Module(module, _) => module
+                .struct_defs()
+                .iter()
+                .find(|def| {
+                    let handle = module.struct_handle_at(def.struct_handle);
+                    module.identifier_at(handle.name).as_str() == need_name
+                })
+                .and_then(|def| {
+                    let handle = module.struct_handle_at(def.struct_handle);
+                    let fields = match &def.field_information {
+                        StructFieldInformation::Native => return None,
+                        StructFieldInformation::Declared(fields) => fields
+                            .iter()
+                            .map(|field| Field {
+                                name: module.identifier_at(field.name).to_string(),
+                                tp: make_type(&field.signature.0, module),
+                            })
+                            .collect(),
+                    };
+
+                    Some(Struct {
+                        name: module.identifier_at(handle.name).to_string(),
+                        abilities: TypeAbilities::from(&handle.abilities),
+                        type_parameters: handle
+                            .type_parameters
+                            .iter()
+                            .map(|tp| TypeAbilities::from(&tp.constraints))
+                            .collect(),
+                        fields,
+                    })
+                }),
+        }
+    }
 }
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Script {
     pub name: String,
+    /// Function definition index.
+    pub index: u16,
     pub parameters: Vec<Type>,
     pub type_parameters: Vec<TypeAbilities>,
     pub returns: Vec<Type>,
@@ -131,6 +211,20 @@ impl Script {
 }
 
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Struct {
+    pub name: String,
+    pub abilities: TypeAbilities,
+    pub type_parameters: Vec<TypeAbilities>,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub tp: Type,
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub enum Type {
     Bool,
     U8,
@@ -145,7 +239,92 @@ pub enum Type {
     TypeParameter(u16),
 }
 
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+impl Type {
+    /// Replaces type parameters with the given type arguments.
+    pub fn instantiate(&self, type_args: &[Type]) -> Type {
+        match self {
+            Type::TypeParameter(idx) => type_args
+                .get(*idx as usize)
+                .cloned()
+                .unwrap_or(Type::TypeParameter(*idx)),
+            Type::Vector(tp) => Type::Vector(Box::new(tp.instantiate(type_args))),
+            Type::Reference(tp) => Type::Reference(Box::new(tp.instantiate(type_args))),
+            Type::MutableReference(tp) => {
+                Type::MutableReference(Box::new(tp.instantiate(type_args)))
+            }
+            Type::Struct(def) => Type::Struct(StructDef {
+                address: def.address,
+                module_name: def.module_name.clone(),
+                name: def.name.clone(),
+                type_parameters: def
+                    .type_parameters
+                    .iter()
+                    .map(|tp| tp.instantiate(type_args))
+                    .collect(),
+            }),
+            Type::Bool | Type::U8 | Type::U64 | Type::U128 | Type::Address | Type::Signer => {
+                self.clone()
+            }
+        }
+    }
+}
+
+impl From<&TypeTag> for Type {
+    fn from(tag: &TypeTag) -> Self {
+        match tag {
+            TypeTag::Bool => Type::Bool,
+            TypeTag::U8 => Type::U8,
+            TypeTag::U64 => Type::U64,
+            TypeTag::U128 => Type::U128,
+            TypeTag::Address => Type::Address,
+            TypeTag::Signer => Type::Signer,
+            TypeTag::Vector(tp) => Type::Vector(Box::new(Type::from(tp.as_ref()))),
+            TypeTag::Struct(tag) => Type::Struct(StructDef {
+                address: tag.address,
+                module_name: tag.module.to_string(),
+                name: tag.name.to_string(),
+                type_parameters: tag.type_params.iter().map(Type::from).collect(),
+            }),
+        }
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Bool => write!(f, "bool"),
+            Type::U8 => write!(f, "u8"),
+            Type::U64 => write!(f, "u64"),
+            Type::U128 => write!(f, "u128"),
+            Type::Address => write!(f, "address"),
+            Type::Signer => write!(f, "signer"),
+            Type::Vector(tp) => write!(f, "vector<{}>", tp),
+            Type::Struct(def) => {
+                write!(
+                    f,
+                    "{}::{}::{}",
+                    def.address.to_hex_literal(),
+                    def.module_name,
+                    def.name
+                )?;
+                if !def.type_parameters.is_empty() {
+                    let tps = def
+                        .type_parameters
+                        .iter()
+                        .map(|tp| tp.to_string())
+                        .collect::<Vec<_>>();
+                    write!(f, "<{}>", tps.join(", "))?;
+                }
+                Ok(())
+            }
+            Type::Reference(tp) => write!(f, "&{}", tp),
+            Type::MutableReference(tp) => write!(f, "&mut {}", tp),
+            Type::TypeParameter(idx) => write!(f, "T{}", idx),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct StructDef {
     pub address: AccountAddress,
     pub module_name: String,
@@ -0,0 +1,313 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use anyhow::{anyhow, ensure, Result};
+
+use move_core_types::language_storage::{ModuleId, StructTag};
+use move_core_types::account_address::AccountAddress;
+
+use crate::fixture::{module_key, resource_key};
+use crate::{Net, Block, BytesForBlock};
+
+/// Caching wrapper over the node backend.
+///
+/// All responses are kept in memory for the lifetime of the wrapper.
+/// Responses for a pinned block are immutable, so they are also stored on disk
+/// in `cache_dir` and never expire. Responses for the latest block and responses of backends
+/// that ignore the height are not persisted.
+pub struct CachedNet {
+    inner: Box<dyn Net>,
+    cache_dir: Option<PathBuf>,
+    memory: RefCell<HashMap<CacheKey, Option<BytesForBlock>>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    kind: &'static str,
+    key: String,
+    height: Option<Block>,
+}
+
+impl CachedNet {
+    /// Wraps the backend. `cache_dir` is the on-disk cache of the node, `None` disables it.
+    pub fn new(inner: Box<dyn Net>, cache_dir: Option<PathBuf>) -> CachedNet {
+        CachedNet {
+            inner,
+            cache_dir,
+            memory: Default::default(),
+        }
+    }
+
+    /// Drops all cached entries, including the on-disk cache.
+    pub fn invalidate(&self) -> Result<()> {
+        self.memory.borrow_mut().clear();
+        if let Some(dir) = &self.cache_dir {
+            if dir.exists() {
+                fs::remove_dir_all(dir)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn get<F>(&self, key: CacheKey, load: F) -> Result<Option<BytesForBlock>>
+    where
+        F: FnOnce() -> Result<Option<BytesForBlock>>,
+    {
+        if let Some(value) = self.memory.borrow().get(&key) {
+            return Ok(value.clone());
+        }
+
+        let path = self.disk_path(&key)?;
+        let value = match path.as_ref().filter(|path| path.exists()) {
+            Some(path) => Some(BytesForBlock(
+                fs::read(path)?,
+                key.height.clone().unwrap_or_default(),
+            )),
+            None => {
+                let value = load()?;
+                if let (Some(path), Some(value)) = (&path, &value) {
+                    if let Some(parent) = path.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::write(path, &value.0)?;
+                }
+                value
+            }
+        };
+
+        self.memory.borrow_mut().insert(key, value.clone());
+        Ok(value)
+    }
+
+    fn is_cached(&self, key: &CacheKey) -> Result<bool> {
+        if self.memory.borrow().contains_key(key) {
+            return Ok(true);
+        }
+        Ok(self
+            .disk_path(key)?
+            .map(|path| path.exists())
+            .unwrap_or_default())
+    }
+
+    fn disk_path(&self, key: &CacheKey) -> Result<Option<PathBuf>> {
+        let (height, dir) = match (&key.height, &self.cache_dir) {
+            (Some(height), Some(dir)) if self.inner.honors_height() => (height, dir),
+            _ => return Ok(None),
+        };
+        ensure!(
+            !height.is_empty()
+                && height
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-'),
+            "Invalid block height '{}'",
+            height
+        );
+        Ok(Some(
+            dir.join(height)
+                .join(key.kind)
+                .join(hex::encode(key.key.as_bytes())),
+        ))
+    }
+}
+
+impl Net for CachedNet {
+    fn get_module(
+        &self,
+        module_id: &ModuleId,
+        height: &Option<Block>,
+    ) -> Result<Option<BytesForBlock>> {
+        let key = CacheKey {
+            kind: "modules",
+            key: module_key(module_id),
+            height: height.clone(),
+        };
+        self.get(key, || self.inner.get_module(module_id, height))
+    }
+
+    fn get_modules(
+        &self,
+        module_ids: &[ModuleId],
+        height: &Option<Block>,
+    ) -> Result<Vec<Option<BytesForBlock>>> {
+        let key = |module_id: &ModuleId| CacheKey {
+            kind: "modules",
+            key: module_key(module_id),
+            height: height.clone(),
+        };
+
+        // Missing modules are loaded in one batch.
+        let mut missing = Vec::new();
+        for module_id in module_ids {
+            if !missing.contains(module_id) && !self.is_cached(&key(module_id))? {
+                missing.push(module_id.clone());
+            }
+        }
+        let loaded = self.inner.get_modules(&missing, height)?;
+        ensure!(
+            loaded.len() == missing.len(),
+            "Expected {} modules, got {}",
+            missing.len(),
+            loaded.len()
+        );
+        let mut loaded = missing.into_iter().zip(loaded).collect::<HashMap<_, _>>();
+
+        module_ids
+            .iter()
+            .map(|module_id| {
+                let value = loaded.remove(module_id);
+                self.get(key(module_id), || {
+                    value.ok_or_else(|| anyhow!("Module {} is not loaded", module_id))
+                })
+            })
+            .collect()
+    }
+
+    fn get_resource(
+        &self,
+        address: &AccountAddress,
+        tag: &StructTag,
+        height: &Option<Block>,
+    ) -> Result<Option<BytesForBlock>> {
+        let key = CacheKey {
+            kind: "resources",
+            key: resource_key(address, tag),
+            height: height.clone(),
+        };
+        self.get(key, || self.inner.get_resource(address, tag, height))
+    }
+
+    fn list_resources(
+        &self,
+        address: &AccountAddress,
+        height: &Option<Block>,
+    ) -> Result<Option<Vec<StructTag>>> {
+        self.inner.list_resources(address, height)
+    }
+
+    fn honors_height(&self) -> bool {
+        self.inner.honors_height()
+    }
+
+    fn latest_block(&self) -> Result<Option<Block>> {
+        self.inner.latest_block()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use anyhow::Result;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::{ModuleId, StructTag};
+
+    use super::CachedNet;
+    use crate::{Block, BytesForBlock, Net};
+
+    /// Counts module requests. The flag tells whether the backend honors the height.
+    struct Counter(Rc<Cell<usize>>, bool);
+
+    impl Net for Counter {
+        fn get_module(
+            &self,
+            _module_id: &ModuleId,
+            height: &Option<Block>,
+        ) -> Result<Option<BytesForBlock>> {
+            self.0.set(self.0.get() + 1);
+            Ok(Some(BytesForBlock(
+                vec![1, 2, 3],
+                height.clone().unwrap_or_else(|| "100".to_string()),
+            )))
+        }
+
+        fn get_resource(
+            &self,
+            _address: &AccountAddress,
+            _tag: &StructTag,
+            _height: &Option<Block>,
+        ) -> Result<Option<BytesForBlock>> {
+            Ok(None)
+        }
+
+        fn honors_height(&self) -> bool {
+            self.1
+        }
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = std::env::temp_dir().join("net_cache_test");
+        let requests = Rc::new(Cell::new(0));
+        let id = ModuleId::new(
+            AccountAddress::from_hex_literal("0x1").unwrap(),
+            Identifier::new("Hash").unwrap(),
+        );
+        let pinned = Some("10".to_string());
+
+        let net = CachedNet::new(Box::new(Counter(requests.clone(), true)), Some(dir.clone()));
+        net.invalidate().unwrap();
+        net.get_module(&id, &None).unwrap();
+        net.get_module(&id, &None).unwrap();
+        net.get_module(&id, &pinned).unwrap();
+        net.get_module(&id, &pinned).unwrap();
+        assert_eq!(requests.get(), 2);
+
+        // Pinned modules are loaded from disk by a new instance.
+        let net = CachedNet::new(Box::new(Counter(requests.clone(), true)), Some(dir.clone()));
+        let module = net.get_module(&id, &pinned).unwrap().unwrap();
+        assert_eq!(module.0, vec![1, 2, 3]);
+        assert_eq!(module.1, "10");
+        net.get_module(&id, &None).unwrap();
+        assert_eq!(requests.get(), 3);
+
+        let other = ModuleId::new(
+            AccountAddress::from_hex_literal("0x1").unwrap(),
+            Identifier::new("Signer").unwrap(),
+        );
+        let modules = net
+            .get_modules(&[other.clone(), id.clone(), other.clone()], &pinned)
+            .unwrap();
+        assert_eq!(modules.len(), 3);
+        assert!(modules.iter().all(|module| module.is_some()));
+        assert_eq!(requests.get(), 4);
+        assert!(net.get_module(&id, &Some("../10".to_string())).is_err());
+
+        net.invalidate().unwrap();
+        net.get_module(&id, &pinned).unwrap();
+        assert_eq!(requests.get(), 5);
+        net.invalidate().unwrap();
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_cache_ignored_height() {
+        let dir = std::env::temp_dir().join("net_cache_ignored_height_test");
+        let requests = Rc::new(Cell::new(0));
+        let id = ModuleId::new(
+            AccountAddress::from_hex_literal("0x1").unwrap(),
+            Identifier::new("Hash").unwrap(),
+        );
+        let pinned = Some("10".to_string());
+
+        // Responses of a backend that ignores the height are not persisted.
+        let net = CachedNet::new(
+            Box::new(Counter(requests.clone(), false)),
+            Some(dir.clone()),
+        );
+        net.invalidate().unwrap();
+        net.get_module(&id, &pinned).unwrap();
+        net.get_module(&id, &pinned).unwrap();
+        assert_eq!(requests.get(), 1);
+        assert!(!dir.exists());
+
+        let net = CachedNet::new(
+            Box::new(Counter(requests.clone(), false)),
+            Some(dir.clone()),
+        );
+        net.get_module(&id, &pinned).unwrap();
+        assert_eq!(requests.get(), 2);
+    }
+}
@@ -58,4 +58,8 @@ impl Net for DnodeNet {
             }
         }
     }
+
+    fn honors_height(&self) -> bool {
+        true
+    }
 }
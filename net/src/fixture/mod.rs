@@ -0,0 +1,548 @@
+use std::cell::{Cell, RefCell};
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context, Result};
+use serde::{Serialize, Deserialize};
+
+use move_core_types::language_storage::{ModuleId, StructTag};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::parser::parse_struct_tag;
+
+use crate::{Net, Block, BytesForBlock};
+
+/// Node responses recorded to a file.
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct Fixture {
+    /// Module responses keyed by `0xADDRESS::Module`.
+    #[serde(default)]
+    pub modules: Vec<FixtureEntry>,
+    /// Resource responses keyed by `0xADDRESS/0xADDRESS::Module::Struct<..>`.
+    #[serde(default)]
+    pub resources: Vec<FixtureEntry>,
+    /// Resource lists keyed by `0xADDRESS`.
+    #[serde(default)]
+    pub resource_lists: Vec<FixtureList>,
+    /// Latest block at the time of recording.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest_block: Option<Block>,
+}
+
+/// A single recorded response.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FixtureEntry {
+    /// Request key.
+    pub key: String,
+    /// Requested block. `None` for the latest block.
+    #[serde(default)]
+    pub height: Option<Block>,
+    /// Block of the response.
+    #[serde(default)]
+    pub block: Block,
+    /// Hex encoded bytes. `None` if the value was not found.
+    pub bytes: Option<String>,
+}
+
+/// A recorded list of the account resources.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct FixtureList {
+    /// Account address.
+    pub key: String,
+    /// Requested block. `None` for the latest block.
+    #[serde(default)]
+    pub height: Option<Block>,
+    /// Resource tags. `None` if the backend is not able to enumerate resources.
+    pub tags: Option<Vec<String>>,
+}
+
+impl Fixture {
+    /// Loads the fixture from a JSON file.
+    pub fn load(path: &Path) -> Result<Fixture> {
+        let content =
+            fs::read(path).with_context(|| format!("Failed to read fixture {:?}", path))?;
+        serde_json::from_slice(&content)
+            .with_context(|| format!("Failed to parse fixture {:?}", path))
+    }
+
+    /// Writes the fixture to a JSON file.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write fixture {:?}", path))
+    }
+
+    /// Returns the recorded module response.
+    pub fn module(&self, module_id: &ModuleId, height: &Option<Block>) -> Option<&FixtureEntry> {
+        find(&self.modules, &module_key(module_id), height)
+    }
+
+    /// Returns the recorded resource response.
+    pub fn resource(
+        &self,
+        address: &AccountAddress,
+        tag: &StructTag,
+        height: &Option<Block>,
+    ) -> Option<&FixtureEntry> {
+        find(&self.resources, &resource_key(address, tag), height)
+    }
+
+    /// Returns the recorded resource list.
+    pub fn resource_list(
+        &self,
+        address: &AccountAddress,
+        height: &Option<Block>,
+    ) -> Option<&FixtureList> {
+        let key = address.to_hex_literal();
+        self.resource_lists
+            .iter()
+            .find(|list| list.key == key && &list.height == height)
+    }
+
+    /// Records the module response. Replaces the previous response to the same request.
+    pub fn record_module(
+        &mut self,
+        module_id: &ModuleId,
+        height: &Option<Block>,
+        bytes: &Option<BytesForBlock>,
+    ) {
+        insert(
+            &mut self.modules,
+            FixtureEntry::new(module_key(module_id), height, bytes),
+        );
+    }
+
+    /// Records the resource response. Replaces the previous response to the same request.
+    pub fn record_resource(
+        &mut self,
+        address: &AccountAddress,
+        tag: &StructTag,
+        height: &Option<Block>,
+        bytes: &Option<BytesForBlock>,
+    ) {
+        insert(
+            &mut self.resources,
+            FixtureEntry::new(resource_key(address, tag), height, bytes),
+        );
+    }
+
+    /// Records the resource list. Replaces the previous list for the same request.
+    pub fn record_resource_list(
+        &mut self,
+        address: &AccountAddress,
+        height: &Option<Block>,
+        tags: &Option<Vec<StructTag>>,
+    ) {
+        let list = FixtureList {
+            key: address.to_hex_literal(),
+            height: height.clone(),
+            tags: tags
+                .as_ref()
+                .map(|tags| tags.iter().map(|tag| tag.to_string()).collect()),
+        };
+        match self
+            .resource_lists
+            .iter_mut()
+            .find(|entry| entry.key == list.key && entry.height == list.height)
+        {
+            Some(entry) => *entry = list,
+            None => self.resource_lists.push(list),
+        }
+    }
+}
+
+impl FixtureEntry {
+    fn new(key: String, height: &Option<Block>, bytes: &Option<BytesForBlock>) -> FixtureEntry {
+        FixtureEntry {
+            key,
+            height: height.clone(),
+            block: bytes
+                .as_ref()
+                .map(|bytes| bytes.1.clone())
+                .unwrap_or_default(),
+            bytes: bytes
+                .as_ref()
+                .map(|bytes| format!("0x{}", hex::encode(&bytes.0))),
+        }
+    }
+
+    fn to_bytes(&self) -> Result<Option<BytesForBlock>> {
+        self.bytes
+            .as_ref()
+            .map(|bytes| {
+                Ok(BytesForBlock(
+                    hex::decode(bytes.trim_start_matches("0x"))?,
+                    self.block.clone(),
+                ))
+            })
+            .transpose()
+    }
+}
+
+/// Fixture key of the module.
+pub fn module_key(module_id: &ModuleId) -> String {
+    format!(
+        "{}::{}",
+        module_id.address().to_hex_literal(),
+        module_id.name()
+    )
+}
+
+/// Fixture key of the resource.
+pub fn resource_key(address: &AccountAddress, tag: &StructTag) -> String {
+    format!("{}/{}", address.to_hex_literal(), tag)
+}
+
+fn find<'a>(
+    entries: &'a [FixtureEntry],
+    key: &str,
+    height: &Option<Block>,
+) -> Option<&'a FixtureEntry> {
+    entries
+        .iter()
+        .find(|entry| entry.key == key && &entry.height == height)
+}
+
+fn insert(entries: &mut Vec<FixtureEntry>, entry: FixtureEntry) {
+    match entries
+        .iter_mut()
+        .find(|old| old.key == entry.key && old.height == entry.height)
+    {
+        Some(old) => *old = entry,
+        None => entries.push(entry),
+    }
+}
+
+/// Serves recorded node responses. Requests missing in the fixture are errors.
+pub struct FixtureNet {
+    fixture: Fixture,
+}
+
+impl FixtureNet {
+    /// Loads the fixture from a JSON file.
+    pub fn new(path: &Path) -> Result<FixtureNet> {
+        Ok(FixtureNet {
+            fixture: Fixture::load(path)?,
+        })
+    }
+}
+
+impl Net for FixtureNet {
+    fn get_module(
+        &self,
+        module_id: &ModuleId,
+        height: &Option<Block>,
+    ) -> Result<Option<BytesForBlock>> {
+        match self.fixture.module(module_id, height) {
+            Some(entry) => entry.to_bytes(),
+            None => bail!(
+                "Module {} at block {} is not recorded in the fixture",
+                module_key(module_id),
+                height.as_deref().unwrap_or("latest")
+            ),
+        }
+    }
+
+    fn get_resource(
+        &self,
+        address: &AccountAddress,
+        tag: &StructTag,
+        height: &Option<Block>,
+    ) -> Result<Option<BytesForBlock>> {
+        match self.fixture.resource(address, tag, height) {
+            Some(entry) => entry.to_bytes(),
+            None => bail!(
+                "Resource {} at block {} is not recorded in the fixture",
+                resource_key(address, tag),
+                height.as_deref().unwrap_or("latest")
+            ),
+        }
+    }
+
+    /// Lists that are not recorded are served as if the backend can't enumerate resources.
+    fn list_resources(
+        &self,
+        address: &AccountAddress,
+        height: &Option<Block>,
+    ) -> Result<Option<Vec<StructTag>>> {
+        match self.fixture.resource_list(address, height) {
+            Some(FixtureList {
+                tags: Some(tags), ..
+            }) => Ok(Some(
+                tags.iter()
+                    .map(|tag| parse_struct_tag(tag))
+                    .collect::<Result<_>>()?,
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn honors_height(&self) -> bool {
+        true
+    }
+
+    fn latest_block(&self) -> Result<Option<Block>> {
+        Ok(self.fixture.latest_block.clone())
+    }
+}
+
+/// Records responses of the wrapped backend to a fixture file.
+/// Responses are collected in memory and written by [`RecordingNet::flush`] or on drop.
+/// The file can be replayed with [`FixtureNet`].
+pub struct RecordingNet {
+    net: Box<dyn Net>,
+    path: PathBuf,
+    fixture: RefCell<Fixture>,
+    /// `true` if the fixture has responses that are not written yet.
+    dirty: Cell<bool>,
+}
+
+impl RecordingNet {
+    /// Wraps the backend. Responses are appended to the fixture file if it exists.
+    pub fn new(net: Box<dyn Net>, path: &Path) -> Result<RecordingNet> {
+        let fixture = if path.exists() {
+            Fixture::load(path)?
+        } else {
+            Fixture::default()
+        };
+        Ok(RecordingNet {
+            net,
+            path: path.to_owned(),
+            fixture: RefCell::new(fixture),
+            dirty: Cell::new(false),
+        })
+    }
+
+    /// Writes the recorded responses to the fixture file.
+    pub fn flush(&self) -> Result<()> {
+        if self.dirty.get() {
+            self.fixture.borrow().save(&self.path)?;
+            self.dirty.set(false);
+        }
+        Ok(())
+    }
+
+    fn record<F: FnOnce(&mut Fixture)>(&self, f: F) {
+        f(&mut self.fixture.borrow_mut());
+        self.dirty.set(true);
+    }
+}
+
+impl Drop for RecordingNet {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            eprintln!("{:?}", err);
+        }
+    }
+}
+
+impl Net for RecordingNet {
+    fn get_module(
+        &self,
+        module_id: &ModuleId,
+        height: &Option<Block>,
+    ) -> Result<Option<BytesForBlock>> {
+        let bytes = self.net.get_module(module_id, height)?;
+        self.record(|fixture| fixture.record_module(module_id, height, &bytes));
+        Ok(bytes)
+    }
+
+    fn get_modules(
+        &self,
+        module_ids: &[ModuleId],
+        height: &Option<Block>,
+    ) -> Result<Vec<Option<BytesForBlock>>> {
+        let modules = self.net.get_modules(module_ids, height)?;
+        self.record(|fixture| {
+            for (module_id, bytes) in module_ids.iter().zip(&modules) {
+                fixture.record_module(module_id, height, bytes);
+            }
+        });
+        Ok(modules)
+    }
+
+    fn get_resource(
+        &self,
+        address: &AccountAddress,
+        tag: &StructTag,
+        height: &Option<Block>,
+    ) -> Result<Option<BytesForBlock>> {
+        let bytes = self.net.get_resource(address, tag, height)?;
+        self.record(|fixture| fixture.record_resource(address, tag, height, &bytes));
+        Ok(bytes)
+    }
+
+    fn list_resources(
+        &self,
+        address: &AccountAddress,
+        height: &Option<Block>,
+    ) -> Result<Option<Vec<StructTag>>> {
+        let tags = self.net.list_resources(address, height)?;
+        self.record(|fixture| fixture.record_resource_list(address, height, &tags));
+        Ok(tags)
+    }
+
+    fn honors_height(&self) -> bool {
+        self.net.honors_height()
+    }
+
+    fn latest_block(&self) -> Result<Option<Block>> {
+        let block = self.net.latest_block()?;
+        self.record(|fixture| fixture.latest_block = block.clone());
+        Ok(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::{ModuleId, StructTag};
+
+    use anyhow::Result;
+
+    use super::{FixtureNet, RecordingNet};
+    use crate::{Block, BytesForBlock, Net};
+
+    /// Backend with a single module and a single resource at block 7.
+    struct StubNet;
+
+    impl Net for StubNet {
+        fn get_module(
+            &self,
+            module_id: &ModuleId,
+            _height: &Option<Block>,
+        ) -> Result<Option<BytesForBlock>> {
+            Ok(if module_id.name().as_str() == "Hash" {
+                Some(BytesForBlock(vec![0xa1, 0x1c], "7".to_string()))
+            } else {
+                None
+            })
+        }
+
+        fn get_resource(
+            &self,
+            _address: &AccountAddress,
+            _tag: &StructTag,
+            _height: &Option<Block>,
+        ) -> Result<Option<BytesForBlock>> {
+            Ok(Some(BytesForBlock(vec![1, 0, 0, 0], "7".to_string())))
+        }
+
+        fn list_resources(
+            &self,
+            _address: &AccountAddress,
+            _height: &Option<Block>,
+        ) -> Result<Option<Vec<StructTag>>> {
+            Ok(Some(vec![store_tag()]))
+        }
+
+        fn latest_block(&self) -> Result<Option<Block>> {
+            Ok(Some("7".to_string()))
+        }
+    }
+
+    fn store_tag() -> StructTag {
+        StructTag {
+            address: AccountAddress::ONE,
+            module: Identifier::new("Store").unwrap(),
+            name: Identifier::new("U64").unwrap(),
+            type_params: vec![],
+        }
+    }
+
+    #[test]
+    fn test_fixture_net() {
+        let path = std::env::temp_dir().join("net_fixture_test.json");
+        fs::write(
+            &path,
+            r#"{
+                "modules": [
+                    {"key": "0x1::Hash", "block": "10", "bytes": "0xa11ceb0b"}
+                ],
+                "resources": [
+                    {"key": "0x1/0x1::Store::U64", "height": "5", "block": "5", "bytes": null}
+                ]
+            }"#,
+        )
+        .unwrap();
+        let net = FixtureNet::new(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let addr = AccountAddress::from_hex_literal("0x1").unwrap();
+        let module = net
+            .get_module(
+                &ModuleId::new(addr, Identifier::new("Hash").unwrap()),
+                &None,
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(module.0, vec![0xa1, 0x1c, 0xeb, 0x0b]);
+        assert_eq!(module.1, "10");
+
+        let tag = StructTag {
+            address: addr,
+            module: Identifier::new("Store").unwrap(),
+            name: Identifier::new("U64").unwrap(),
+            type_params: vec![],
+        };
+        assert!(net
+            .get_resource(&addr, &tag, &Some("5".to_string()))
+            .unwrap()
+            .is_none());
+        net.get_resource(&addr, &tag, &None).unwrap_err();
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let path = std::env::temp_dir().join("net_fixture_record_test.json");
+        if path.exists() {
+            fs::remove_file(&path).unwrap();
+        }
+        let hash = ModuleId::new(AccountAddress::ONE, Identifier::new("Hash").unwrap());
+        let missing = ModuleId::new(AccountAddress::ONE, Identifier::new("Missing").unwrap());
+        let height = Some("7".to_string());
+
+        let recording = RecordingNet::new(Box::new(StubNet), &path).unwrap();
+        let modules = recording
+            .get_modules(&[hash.clone(), missing.clone()], &None)
+            .unwrap();
+        let resource = recording
+            .get_resource(&AccountAddress::ONE, &store_tag(), &height)
+            .unwrap();
+        let tags = recording
+            .list_resources(&AccountAddress::ONE, &height)
+            .unwrap();
+        assert_eq!(recording.latest_block().unwrap(), height);
+        assert!(!path.exists());
+        recording.flush().unwrap();
+
+        let replay = FixtureNet::new(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let module = replay.get_module(&hash, &None).unwrap().unwrap();
+        assert_eq!(module.0, modules[0].as_ref().unwrap().0);
+        assert_eq!(module.1, "7");
+        assert!(replay.get_module(&missing, &None).unwrap().is_none());
+        replay.get_module(&hash, &height).unwrap_err();
+        assert_eq!(replay.latest_block().unwrap(), height);
+
+        let replayed = replay
+            .get_resource(&AccountAddress::ONE, &store_tag(), &height)
+            .unwrap()
+            .unwrap();
+        assert_eq!(replayed.0, resource.unwrap().0);
+        assert_eq!(
+            replay
+                .list_resources(&AccountAddress::ONE, &height)
+                .unwrap(),
+            tags
+        );
+        assert!(replay
+            .list_resources(&AccountAddress::ONE, &None)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -1,6 +1,13 @@
-use anyhow::{Error, Result};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::str::FromStr;
+use anyhow::{anyhow, bail, Error, Result};
 use url::Url;
 
+use move_binary_format::access::ModuleAccess;
+use move_binary_format::CompiledModule;
 use move_core_types::account_address::AccountAddress;
 use move_core_types::language_storage::{ModuleId, StructTag};
 use move_core_types::resolver::{ModuleResolver, ResourceResolver};
@@ -8,23 +15,142 @@ use move_core_types::resolver::{ModuleResolver, ResourceResolver};
 #[cfg(feature = "dfinance")]
 mod dnode;
 
+pub mod cache;
+pub mod fixture;
+#[cfg(feature = "local")]
+mod local;
 mod pont;
+use crate::fixture::FixtureNet;
 use crate::pont::PontNet;
 
+#[cfg(feature = "local")]
+pub use crate::local::LOCAL_BLOCK;
+
 pub type Block = String;
 
-pub fn make_net<T>(uri: T) -> Result<Box<dyn Net>>
+/// Node backend kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetKind {
+    /// Pontem node JSON-RPC.
+    Pontem,
+    /// Dfinance node REST api. Requires the `dfinance` feature.
+    Dnode,
+    /// move-cli sandbox storage directory. Requires the `local` feature.
+    Local,
+    /// JSON file with recorded node responses.
+    Fixture,
+}
+
+impl NetKind {
+    /// Names of the backends.
+    pub const NAMES: [&'static str; 4] = ["pontem", "dnode", "local", "fixture"];
+
+    /// Selects the backend by the url scheme:
+    /// `http(s)` - pontem, `dnode+http(s)` - dnode,
+    /// `file` - local for a directory and fixture for a file.
+    pub fn from_url(uri: &Url) -> Result<NetKind> {
+        Ok(match uri.scheme() {
+            "http" | "https" => NetKind::Pontem,
+            "dnode+http" | "dnode+https" => NetKind::Dnode,
+            "file" => {
+                let path = file_path(uri)?;
+                if path.is_dir() {
+                    NetKind::Local
+                } else {
+                    NetKind::Fixture
+                }
+            }
+            scheme => bail!(
+                "Unsupported url scheme '{}'. Expected one of: http, https, dnode+http, dnode+https, file",
+                scheme
+            ),
+        })
+    }
+}
+
+impl FromStr for NetKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "pontem" => NetKind::Pontem,
+            "dnode" => NetKind::Dnode,
+            "local" => NetKind::Local,
+            "fixture" => NetKind::Fixture,
+            _ => bail!(
+                "Unknown net kind '{}'. Expected one of: {}",
+                s,
+                NetKind::NAMES.join(", ")
+            ),
+        })
+    }
+}
+
+impl Display for NetKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            NetKind::Pontem => NetKind::NAMES[0],
+            NetKind::Dnode => NetKind::NAMES[1],
+            NetKind::Local => NetKind::NAMES[2],
+            NetKind::Fixture => NetKind::NAMES[3],
+        };
+        f.write_str(name)
+    }
+}
+
+/// Creates the node backend.
+/// The backend is selected by `kind` or by the url scheme if `kind` is not specified.
+/// `build_dir` is used by the local backend to resolve compiled modules.
+pub fn make_net<T>(uri: T, kind: Option<NetKind>, build_dir: &Path) -> Result<Box<dyn Net>>
 where
     T: Into<Url>,
 {
     let uri = uri.into();
+    let kind = match kind {
+        Some(kind) => kind,
+        None => NetKind::from_url(&uri)?,
+    };
+
+    Ok(match kind {
+        NetKind::Pontem => Box::new(PontNet::new(uri.to_string())),
+        NetKind::Dnode => make_dnode_net(uri)?,
+        NetKind::Local => make_local_net(build_dir, &file_path(&uri)?)?,
+        NetKind::Fixture => Box::new(FixtureNet::new(&file_path(&uri)?)?),
+    })
+}
+
+#[cfg(feature = "dfinance")]
+fn make_dnode_net(uri: Url) -> Result<Box<dyn Net>> {
+    let uri = match uri.as_str().strip_prefix("dnode+") {
+        Some(uri) => Url::parse(uri)?,
+        None => uri,
+    };
+    Ok(Box::new(dnode::DnodeNet { uri }))
+}
+
+#[cfg(not(feature = "dfinance"))]
+fn make_dnode_net(_uri: Url) -> Result<Box<dyn Net>> {
+    bail!("The dnode backend requires the `dfinance` feature")
+}
+
+fn file_path(uri: &Url) -> Result<std::path::PathBuf> {
+    uri.to_file_path()
+        .map_err(|_| anyhow!("Expected a file url. Actual: {}", uri))
+}
+
+/// Creates access to the move-cli sandbox storage.
+#[cfg(feature = "local")]
+pub fn make_local_net(build_dir: &Path, storage_dir: &Path) -> Result<Box<dyn Net>> {
+    Ok(Box::new(local::LocalNet::new(build_dir, storage_dir)?))
+}
 
-    Ok(Box::new(PontNet {
-        api: uri.to_string(),
-    }))
+/// Creates access to the move-cli sandbox storage.
+#[cfg(not(feature = "local"))]
+pub fn make_local_net(_build_dir: &Path, _storage_dir: &Path) -> Result<Box<dyn Net>> {
+    bail!("The local backend requires the `local` feature")
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BytesForBlock(pub Vec<u8>, pub Block);
 
 pub trait Net {
@@ -34,26 +160,95 @@ pub trait Net {
         height: &Option<Block>,
     ) -> Result<Option<BytesForBlock>>;
 
+    /// Loads modules in one request if the backend supports it.
+    /// The result is in the order of `module_ids`.
+    fn get_modules(
+        &self,
+        module_ids: &[ModuleId],
+        height: &Option<Block>,
+    ) -> Result<Vec<Option<BytesForBlock>>> {
+        module_ids
+            .iter()
+            .map(|module_id| self.get_module(module_id, height))
+            .collect()
+    }
+
     fn get_resource(
         &self,
         address: &AccountAddress,
         tag: &StructTag,
         height: &Option<Block>,
     ) -> Result<Option<BytesForBlock>>;
+
+    /// Returns tags of the resources stored under the address.
+    /// `None` if the backend is not able to enumerate resources.
+    fn list_resources(
+        &self,
+        _address: &AccountAddress,
+        _height: &Option<Block>,
+    ) -> Result<Option<Vec<StructTag>>> {
+        Ok(None)
+    }
+
+    /// Returns `true` if responses reflect the state at the requested height.
+    /// Backends that keep only the latest state return `false`.
+    fn honors_height(&self) -> bool {
+        false
+    }
+
+    /// Returns the latest block to pin the reads to.
+    /// `None` if the backend can't name the block.
+    fn latest_block(&self) -> Result<Option<Block>> {
+        Ok(None)
+    }
 }
 
 pub struct NetView {
     net: Box<dyn Net>,
     block: Option<Block>,
+    modules: RefCell<HashMap<ModuleId, Option<Vec<u8>>>>,
 }
 
 impl NetView {
     pub fn new(net: Box<dyn Net>, block: Option<Block>) -> NetView {
-        NetView { net, block }
+        NetView {
+            net,
+            block,
+            modules: Default::default(),
+        }
     }
 
     pub fn set_block(&mut self, block: Option<Block>) {
         self.block = block;
+        self.modules.borrow_mut().clear();
+    }
+
+    /// Loads the modules with all their dependencies.
+    /// Each level of the dependency tree is loaded in one request.
+    pub fn prefetch_modules(&self, module_ids: Vec<ModuleId>) -> Result<()> {
+        let mut next = module_ids;
+        loop {
+            next.sort();
+            next.dedup();
+            next.retain(|id| !self.modules.borrow().contains_key(id));
+            if next.is_empty() {
+                return Ok(());
+            }
+
+            let modules = self.net.get_modules(&next, &self.block)?;
+            let mut deps = Vec::new();
+            let mut cache = self.modules.borrow_mut();
+            for (id, bytes) in next.into_iter().zip(modules) {
+                let bytes = bytes.map(|bytes| bytes.0);
+                if let Some(bytes) = &bytes {
+                    let module = CompiledModule::deserialize(bytes)
+                        .map_err(|err| anyhow!("Failed to deserialize module {}: {}", id, err))?;
+                    deps.extend(module.immediate_dependencies());
+                }
+                cache.insert(id, bytes);
+            }
+            next = deps;
+        }
     }
 }
 
@@ -61,6 +256,10 @@ impl ModuleResolver for NetView {
     type Error = anyhow::Error;
 
     fn get_module(&self, module_id: &ModuleId) -> anyhow::Result<Option<Vec<u8>>> {
+        if let Some(bytes) = self.modules.borrow().get(module_id) {
+            return Ok(bytes.clone());
+        }
+
         self.net
             .get_module(module_id, &self.block)
             .map(|bytes| bytes.map(|bytes| bytes.0))
@@ -0,0 +1,154 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Result};
+
+use move_cli::sandbox::utils::on_disk_state_view::OnDiskStateView;
+use move_core_types::language_storage::{ModuleId, StructTag};
+use move_core_types::account_address::AccountAddress;
+use move_core_types::parser::parse_struct_tag;
+
+use crate::{Net, Block, BytesForBlock};
+
+/// Block name of the local storage. The sandbox storage keeps only the latest state.
+pub const LOCAL_BLOCK: &str = "local";
+
+/// Access to the move-cli sandbox storage.
+pub struct LocalNet {
+    pub(crate) state: OnDiskStateView,
+    storage_dir: PathBuf,
+}
+
+impl LocalNet {
+    /// Opens the sandbox storage in `storage_dir`.
+    pub fn new(build_dir: &Path, storage_dir: &Path) -> Result<LocalNet> {
+        Ok(LocalNet {
+            state: OnDiskStateView::create(build_dir, storage_dir)?,
+            storage_dir: storage_dir.to_owned(),
+        })
+    }
+}
+
+impl Net for LocalNet {
+    fn get_module(
+        &self,
+        module_id: &ModuleId,
+        height: &Option<Block>,
+    ) -> Result<Option<BytesForBlock>> {
+        check_height(height)?;
+        Ok(self
+            .state
+            .get_module_bytes(module_id)?
+            .map(|bytes| BytesForBlock(bytes, LOCAL_BLOCK.to_string())))
+    }
+
+    fn get_resource(
+        &self,
+        address: &AccountAddress,
+        tag: &StructTag,
+        height: &Option<Block>,
+    ) -> Result<Option<BytesForBlock>> {
+        check_height(height)?;
+        Ok(self
+            .state
+            .get_resource_bytes(*address, tag.clone())?
+            .map(|bytes| BytesForBlock(bytes, LOCAL_BLOCK.to_string())))
+    }
+
+    fn list_resources(
+        &self,
+        address: &AccountAddress,
+        height: &Option<Block>,
+    ) -> Result<Option<Vec<StructTag>>> {
+        check_height(height)?;
+        if !self.storage_dir.exists() {
+            return Ok(Some(vec![]));
+        }
+
+        // Layout: <storage>/0x<address>/resources/<struct tag>.bcs
+        let mut tags = vec![];
+        for account_dir in fs::read_dir(&self.storage_dir)? {
+            let account_dir = account_dir?.path();
+            let account = account_dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| AccountAddress::from_hex_literal(name).ok());
+            if account.as_ref() != Some(address) {
+                continue;
+            }
+
+            let resources_dir = account_dir.join("resources");
+            if !resources_dir.is_dir() {
+                continue;
+            }
+            for resource in fs::read_dir(resources_dir)? {
+                let resource = resource?.path();
+                if resource.extension().and_then(|ext| ext.to_str()) != Some("bcs") {
+                    continue;
+                }
+                if let Some(name) = resource.file_stem().and_then(|name| name.to_str()) {
+                    tags.push(parse_struct_tag(name)?);
+                }
+            }
+        }
+        tags.sort();
+        Ok(Some(tags))
+    }
+}
+
+fn check_height(height: &Option<Block>) -> Result<()> {
+    match height {
+        Some(height) if height != LOCAL_BLOCK => {
+            bail!(
+                "The local storage keeps only the latest state. Block {} is not available.",
+                height
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::StructTag;
+
+    use super::LocalNet;
+    use crate::Net;
+
+    #[test]
+    fn test_get_resource() {
+        let dir = std::env::temp_dir().join("net_local_test_get_resource");
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        let net = LocalNet::new(&dir.join("build"), &dir.join("storage")).unwrap();
+
+        let addr = AccountAddress::from_hex_literal("0x42").unwrap();
+        let tag = StructTag {
+            address: addr,
+            module: Identifier::new("Store").unwrap(),
+            name: Identifier::new("U64").unwrap(),
+            type_params: vec![],
+        };
+        assert!(net.get_resource(&addr, &tag, &None).unwrap().is_none());
+
+        net.state
+            .save_resource(addr, tag.clone(), &[100, 0, 0, 0, 0, 0, 0, 0])
+            .unwrap();
+        let resource = net.get_resource(&addr, &tag, &None).unwrap().unwrap();
+        assert_eq!(resource.0, [100, 0, 0, 0, 0, 0, 0, 0]);
+        assert!(net
+            .get_resource(&addr, &tag, &Some("10".to_string()))
+            .is_err());
+        assert_eq!(net.list_resources(&addr, &None).unwrap(), Some(vec![tag]));
+        assert_eq!(
+            net.list_resources(&AccountAddress::from_hex_literal("0x1").unwrap(), &None)
+                .unwrap(),
+            Some(vec![])
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
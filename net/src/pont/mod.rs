@@ -1,6 +1,9 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 use serde::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
 
 use move_core_types::language_storage::{ModuleId, StructTag};
 use move_core_types::account_address::AccountAddress;
@@ -12,6 +15,19 @@ pub type Block = String;
 
 pub struct PontNet {
     pub(crate) api: String,
+    client: reqwest::blocking::Client,
+    /// Resolved hashes of block numbers.
+    block_hashes: RefCell<HashMap<u64, String>>,
+}
+
+impl PontNet {
+    pub fn new(api: String) -> PontNet {
+        PontNet {
+            api,
+            client: reqwest::blocking::Client::new(),
+            block_hashes: RefCell::new(HashMap::new()),
+        }
+    }
 }
 
 impl Net for PontNet {
@@ -20,44 +36,33 @@ impl Net for PontNet {
         module_id: &ModuleId,
         height: &Option<Block>,
     ) -> Result<Option<BytesForBlock>> {
-        let req = Request {
-            id: 1,
-            jsonrpc: "2.0",
-            method: "mvm_getModule",
-            params: vec![format!("0x{}", hex::encode(bcs::to_bytes(module_id)?))],
-        };
-
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            "Content-Type",
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-        let response = reqwest::blocking::Client::new()
-            .post(&self.api)
-            .headers(headers)
-            .json(&req)
-            .send()?;
+        let at = self.block_hash(height)?;
+        let resp = self
+            .send::<String>(&module_request(1, module_id, &at)?)
+            .map_err(|err| anyhow!("Failed to get module :{}. {}", module_id, err))?;
+        to_bytes(resp, height)
+    }
 
-        if response.status() != 200 {
-            bail!(
-                "Failed to get module :{}. Error:{}",
-                module_id,
-                response.status()
-            );
-        }
-        let resp = response.json::<Response>()?;
-        if let Some(err) = resp.error {
-            bail!("{:?}", err);
-        }
-        if let Some(result) = resp.result {
-            let result = hex::decode(&result[2..])?;
-            Ok(Some(BytesForBlock(
-                result,
-                height.clone().unwrap_or_default(),
-            )))
-        } else {
-            Ok(None)
+    fn get_modules(
+        &self,
+        module_ids: &[ModuleId],
+        height: &Option<Block>,
+    ) -> Result<Vec<Option<BytesForBlock>>> {
+        if module_ids.is_empty() {
+            return Ok(vec![]);
         }
+
+        let at = self.block_hash(height)?;
+        let requests = module_ids
+            .iter()
+            .enumerate()
+            .map(|(id, module_id)| module_request(id as u64, module_id, &at))
+            .collect::<Result<Vec<_>>>()?;
+        self.send_batch::<String>(&requests)
+            .map_err(|err| anyhow!("Failed to get modules. {}", err))?
+            .into_iter()
+            .map(|resp| to_bytes(resp, height))
+            .collect()
     }
 
     fn get_resource(
@@ -66,48 +71,153 @@ impl Net for PontNet {
         tag: &StructTag,
         height: &Option<Block>,
     ) -> Result<Option<BytesForBlock>> {
+        let mut params = vec![
+            address_to_ss58(address),
+            format!("0x{}", hex::encode(bcs::to_bytes(&tag)?)),
+        ];
+        params.extend(self.block_hash(height)?);
         let req = Request {
             id: 1,
             jsonrpc: "2.0",
             method: "mvm_getResource",
-            params: vec![
-                address_to_ss58(address),
-                format!("0x{}", hex::encode(bcs::to_bytes(&tag)?)),
-            ],
+            params,
         };
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            "Content-Type",
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-        let response = reqwest::blocking::Client::new()
-            .post(&self.api)
-            .headers(headers)
-            .json(&req)
-            .send()?;
-        if response.status() != 200 {
-            bail!(
-                "Failed to get resource :{:?} {:?}. Error:{}",
-                &address,
-                &tag,
-                response.status()
+        let resp = self
+            .send::<String>(&req)
+            .map_err(|err| anyhow!("Failed to get resource :{:?} {:?}. {}", address, tag, err))?;
+        to_bytes(resp, height)
+    }
+
+    fn honors_height(&self) -> bool {
+        true
+    }
+
+    fn latest_block(&self) -> Result<Option<Block>> {
+        let req = Request {
+            id: 1,
+            jsonrpc: "2.0",
+            method: "chain_getBlockHash",
+            params: vec![],
+        };
+        let resp = self
+            .send::<String>(&req)
+            .map_err(|err| anyhow!("Failed to get the latest block hash. {}", err))?;
+        if let Some(err) = resp.error {
+            bail!("Failed to get the latest block hash. {}", err);
+        }
+        match resp.result {
+            Some(hash) => Ok(Some(hash)),
+            None => bail!("The latest block not found"),
+        }
+    }
+}
+
+impl PontNet {
+    /// Hash of the block to query the state at. `None` for the latest block.
+    /// The height is either a block hash in hex or a block number.
+    fn block_hash(&self, height: &Option<Block>) -> Result<Option<String>> {
+        let height = match height {
+            Some(height) => height,
+            None => return Ok(None),
+        };
+        if height.starts_with("0x") {
+            ensure!(
+                height.len() == 66 && hex::decode(&height[2..]).is_ok(),
+                "Expected a 32 byte block hash. Actual: {}",
+                height
             );
+            return Ok(Some(height.to_owned()));
         }
 
-        let resp = response.json::<Response>()?;
+        let number = height.parse::<u64>().map_err(|_| {
+            anyhow!(
+                "Expected a block number or a block hash. Actual: {}",
+                height
+            )
+        })?;
+        if let Some(hash) = self.block_hashes.borrow().get(&number) {
+            return Ok(Some(hash.clone()));
+        }
+        let req = Request {
+            id: 1,
+            jsonrpc: "2.0",
+            method: "chain_getBlockHash",
+            params: vec![format!("0x{:x}", number)],
+        };
+        let resp = self
+            .send::<String>(&req)
+            .map_err(|err| anyhow!("Failed to get hash of block {}. {}", number, err))?;
         if let Some(err) = resp.error {
-            bail!("{:?}", err);
+            bail!("Failed to get hash of block {}. {}", number, err);
         }
-        if let Some(result) = resp.result {
-            let result = hex::decode(&result[2..])?;
-            Ok(Some(BytesForBlock(
-                result,
-                height.clone().unwrap_or_default(),
-            )))
-        } else {
-            Ok(None)
+        match resp.result {
+            Some(hash) => {
+                self.block_hashes.borrow_mut().insert(number, hash.clone());
+                Ok(Some(hash))
+            }
+            None => bail!("Block {} not found", number),
         }
     }
+
+    fn post<R: Serialize + ?Sized>(&self, body: &R) -> Result<reqwest::blocking::Response> {
+        let response = self
+            .client
+            .post(&self.api)
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .json(body)
+            .send()?;
+        if response.status() != 200 {
+            bail!("Error:{}", response.status());
+        }
+        Ok(response)
+    }
+
+    fn send<T: DeserializeOwned>(&self, req: &Request) -> Result<Response<T>> {
+        Ok(self.post(req)?.json::<Response<T>>()?)
+    }
+
+    /// Sends requests in one JSON-RPC batch. Responses are returned in the order of requests.
+    fn send_batch<T: DeserializeOwned>(&self, requests: &[Request]) -> Result<Vec<Response<T>>> {
+        let mut responses = self.post(requests)?.json::<Vec<Response<T>>>()?;
+        ensure!(
+            responses.len() == requests.len(),
+            "Expected {} responses in the batch, got {}",
+            requests.len(),
+            responses.len()
+        );
+        responses.sort_by_key(|resp| resp.id);
+        Ok(responses)
+    }
+}
+
+/// `at` is the hash of the block to query the module at.
+fn module_request(id: u64, module_id: &ModuleId, at: &Option<String>) -> Result<Request> {
+    let mut params = vec![format!("0x{}", hex::encode(bcs::to_bytes(module_id)?))];
+    params.extend(at.clone());
+    Ok(Request {
+        id,
+        jsonrpc: "2.0",
+        method: "mvm_getModule",
+        params,
+    })
+}
+
+fn to_bytes(resp: Response, height: &Option<Block>) -> Result<Option<BytesForBlock>> {
+    if let Some(err) = resp.error {
+        bail!("{:?}", err);
+    }
+    if let Some(result) = resp.result {
+        let result = hex::decode(&result[2..])?;
+        Ok(Some(BytesForBlock(
+            result,
+            height.clone().unwrap_or_default(),
+        )))
+    } else {
+        Ok(None)
+    }
 }
 
 #[derive(Serialize)]
@@ -120,10 +230,10 @@ struct Request {
 
 #[allow(dead_code)]
 #[derive(Deserialize, Debug)]
-struct Response {
+struct Response<T = String> {
     id: u64,
     jsonrpc: String,
-    result: Option<String>,
+    result: Option<T>,
     error: Option<ErrorMsg>,
 }
 
@@ -155,13 +265,31 @@ mod tests {
     use super::PontNet;
     use crate::Net;
 
+    #[test]
+    fn test_block_hash() {
+        let api = PontNet::new("http://localhost:9933".to_string());
+        let hash = format!("0x{}", "3f".repeat(32));
+        assert_eq!(api.block_hash(&None).unwrap(), None);
+        assert_eq!(
+            api.block_hash(&Some(hash.clone())).unwrap(),
+            Some(hash.clone())
+        );
+        assert!(api.block_hash(&Some("0x3fd7".to_string())).is_err());
+        assert!(api.block_hash(&Some("latest".to_string())).is_err());
+
+        // Resolved block numbers are not requested again.
+        api.block_hashes.borrow_mut().insert(100, hash.clone());
+        assert_eq!(
+            api.block_hash(&Some("100".to_string())).unwrap(),
+            Some(hash)
+        );
+    }
+
     /// If the node is raised to "localhost:9933".
     #[ignore]
     #[test]
     fn test_get_module() {
-        let api = PontNet {
-            api: "http://localhost:9933".to_string(),
-        };
+        let api = PontNet::new("http://localhost:9933".to_string());
         let module = api
             .get_module(
                 &ModuleId::new(
@@ -189,9 +317,7 @@ mod tests {
     #[ignore]
     #[test]
     fn test_get_resource() {
-        let api = PontNet {
-            api: "http://localhost:9933".to_string(),
-        };
+        let api = PontNet::new("http://localhost:9933".to_string());
 
         let adr = ss58_to_address("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY").unwrap();
         let module = api
@@ -26,6 +26,25 @@ const LIB_VERSION: &str = hash_project::version!("../pontemapi");
 /// Type of function from the library
 type FnInterface = unsafe fn(&str, &str, u64, &str) -> Result<String>;
 
+/// Type of offline signing function from the library
+type FnSignInterface = unsafe fn(&str, u64, &str, u32, &str, u32, u32) -> Result<String>;
+
+/// Type of submitting function from the library
+type FnSubmitInterface = unsafe fn(&str, &str) -> Result<String>;
+
+/// Parameters for offline signing
+#[derive(Debug, Clone)]
+pub struct SignParams {
+    /// Account nonce.
+    pub nonce: u32,
+    /// Genesis hash of the chain in hex.
+    pub genesis_hash: String,
+    /// Runtime spec version.
+    pub spec_version: u32,
+    /// Runtime transaction version.
+    pub transaction_version: u32,
+}
+
 /// Client for publishing module, bundle, transactions to node
 pub struct PontemClient {
     lib: Library,
@@ -142,6 +161,69 @@ impl PontemClient {
         }
     }
 
+    /// Signing the module, package or transaction without connection to the node.
+    /// Returns the signed extrinsic in hex.
+    ///     file_path: The path to the file. PATH/TO/FILE.mv|pac|mvt
+    ///     gas: Gas limit for transaction execution.
+    ///     key_phrase: secret keyphrase
+    ///     params: Nonce, genesis hash and runtime versions.
+    pub fn tx_mvm_sign(
+        &self,
+        file_path: &str,
+        gas: u64,
+        key_phrase: &str,
+        params: &SignParams,
+    ) -> Result<String> {
+        unsafe {
+            let func: libloading::Symbol<FnSignInterface> = self.lib.get(b"tx_mvm_sign")?;
+            func(
+                file_path,
+                gas,
+                key_phrase,
+                params.nonce,
+                &params.genesis_hash,
+                params.spec_version,
+                params.transaction_version,
+            )
+        }
+    }
+
+    /// (DEV) Signing the module, package or transaction without connection to the node.
+    /// Returns the signed extrinsic in hex.
+    ///     file_path: The path to the file. PATH/TO/FILE.mv|pac|mvt
+    ///     gas: Gas limit for transaction execution.
+    ///     test_account: alias or ss58 address of the test account. //Alice, alice, bob... or 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY
+    ///     params: Nonce, genesis hash and runtime versions.
+    pub fn tx_mvm_sign_dev(
+        &self,
+        file_path: &str,
+        gas: u64,
+        test_account: &str,
+        params: &SignParams,
+    ) -> Result<String> {
+        unsafe {
+            let func: libloading::Symbol<FnSignInterface> = self.lib.get(b"tx_mvm_sign_dev")?;
+            func(
+                file_path,
+                gas,
+                test_account,
+                params.nonce,
+                &params.genesis_hash,
+                params.spec_version,
+                params.transaction_version,
+            )
+        }
+    }
+
+    /// Submitting the signed extrinsic. Returns the extrinsic hash.
+    ///     extrinsic: Signed extrinsic in hex.
+    pub fn tx_submit(&self, extrinsic: &str) -> Result<String> {
+        unsafe {
+            let func: libloading::Symbol<FnSubmitInterface> = self.lib.get(b"tx_submit")?;
+            func(extrinsic, self.url.as_str())
+        }
+    }
+
     /// Library Version
     pub fn version(&self) -> Result<String> {
         let result = unsafe {
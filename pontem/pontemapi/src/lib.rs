@@ -1,18 +1,24 @@
 use std::fs;
 use std::str::FromStr;
 use std::path::PathBuf;
-use anyhow::{Result, anyhow, ensure};
+use anyhow::{Result, anyhow, bail, ensure};
+use codec::{Decode, Encode};
 use log::debug;
 use url::{Url, Origin};
 use sp_core::crypto::{AccountId32, Ss58Codec};
 use sp_core::crypto::Pair;
 use sp_core::sr25519::Pair as sr25519Pair;
+use sp_core::H256;
 use sp_keyring::AccountKeyring;
-use subxt::{ClientBuilder, PairSigner};
+use subxt::{ClientBuilder, Encoded, Metadata, PairSigner, RuntimeVersion, UncheckedExtrinsic};
+use frame_metadata::RuntimeMetadataPrefixed;
 
 /// Library version with a short hash
 const VERSION: &str = hash_project::version!(".");
 
+/// Runtime metadata for encoding calls without connection to the node
+const METADATA: &[u8] = include_bytes!("../metadata/pontem.scale");
+
 // metadata for encoding and decoding
 #[subxt::subxt(
     runtime_metadata_path = "metadata/pontem.scale",
@@ -191,6 +197,80 @@ pub fn tx_mvm_publish_package_dev(
     result
 }
 
+/// Public interface for offline signing of the module, package or transaction
+///     file_path: The path to the file. PATH/TO/FILE.mv|pac|mvt
+///     gas: Gas limit for transaction execution.
+///     key_phrase: secret keyphrase
+///     nonce: Account nonce.
+///     genesis_hash: Genesis hash of the chain in hex.
+///     spec_version: Runtime spec version.
+///     transaction_version: Runtime transaction version.
+/// Returns the signed extrinsic in hex.
+#[export_name = "tx_mvm_sign"]
+pub fn tx_mvm_sign(
+    file_path: &str,
+    gas: u64,
+    key_phrase: &str,
+    nonce: u32,
+    genesis_hash: &str,
+    spec_version: u32,
+    transaction_version: u32,
+) -> Result<String> {
+    let pair = sr25519Pair::from_string(key_phrase, None).map_err(|err| anyhow!("{:?}", err))?;
+    let params = SignParams::new(nonce, genesis_hash, spec_version, transaction_version)?;
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(sign(file_path, gas, pair, params))
+}
+
+/// (DEV) Public interface for offline signing of the module, package or transaction
+///     file_path: The path to the file. PATH/TO/FILE.mv|pac|mvt
+///     gas: Gas limit for transaction execution.
+///     test_signer: alias or ss58 address of the test account. //Alice, alice, bob... or 5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY
+///     nonce: Account nonce.
+///     genesis_hash: Genesis hash of the chain in hex.
+///     spec_version: Runtime spec version.
+///     transaction_version: Runtime transaction version.
+/// Returns the signed extrinsic in hex.
+#[export_name = "tx_mvm_sign_dev"]
+pub fn tx_mvm_sign_dev(
+    file_path: &str,
+    gas: u64,
+    test_signer: &str,
+    nonce: u32,
+    genesis_hash: &str,
+    spec_version: u32,
+    transaction_version: u32,
+) -> Result<String> {
+    let pair = test_keyring_from_str(test_signer)?.pair();
+    let params = SignParams::new(nonce, genesis_hash, spec_version, transaction_version)?;
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(sign(file_path, gas, pair, params))
+}
+
+/// Public interface for submitting the signed extrinsic
+///     extrinsic: Signed extrinsic in hex.
+///     url: Node address. ws://127.0.0.1:9944
+/// Returns the extrinsic hash.
+#[export_name = "tx_submit"]
+pub fn tx_submit(extrinsic: &str, url_str: &str) -> Result<String> {
+    let url = Url::from_str(url_str)?;
+    let extrinsic = hex::decode(extrinsic.trim().trim_start_matches("0x"))?;
+
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(submit(extrinsic, url))
+}
+
 /// Library Version
 #[no_mangle]
 pub fn version() -> String {
@@ -293,6 +373,118 @@ async fn pb_package_dev(context: Context) -> Result<String> {
     Ok(hash)
 }
 
+/// Sign a module, package or transaction without connection to the node
+async fn sign(
+    file_path: &str,
+    gas: u64,
+    pair: sr25519Pair,
+    params: SignParams,
+) -> Result<String> {
+    let path_file = PathBuf::from_str(file_path)?;
+    debug!("Reading a file: {}", path_file.display());
+    let content = fs::read(&path_file)?;
+
+    let metadata = Metadata::try_from(RuntimeMetadataPrefixed::decode(&mut &METADATA[..])?)?;
+    let ext = path_file
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+    let call = match ext {
+        "mv" => encode_call(
+            &metadata,
+            pontem::mvm::calls::PublishModule {
+                module_bc: content,
+                gas_limit: gas,
+            },
+        )?,
+        "pac" => encode_call(
+            &metadata,
+            pontem::mvm::calls::PublishPackage {
+                package: content,
+                gas_limit: gas,
+            },
+        )?,
+        "mvt" => encode_call(
+            &metadata,
+            pontem::mvm::calls::Execute {
+                tx_bc: content,
+                gas_limit: gas,
+            },
+        )?,
+        _ => bail!(
+            "pac, mv or mvt extension was expected\n{}",
+            path_file.display()
+        ),
+    };
+
+    let signer_pair: PairSigner<DefaultConfig, sr25519Pair> = PairSigner::new(pair);
+    let extrinsic = subxt::extrinsic::create_signed(
+        &params.runtime_version,
+        params.genesis_hash,
+        params.nonce,
+        call,
+        &signer_pair,
+    )
+    .await?;
+
+    Ok(format!("0x{}", hex::encode(extrinsic.encode())))
+}
+
+/// Submit the signed extrinsic
+async fn submit(extrinsic: Vec<u8>, url: Url) -> Result<String> {
+    let extrinsic = UncheckedExtrinsic::<DefaultConfig>::decode(&mut &extrinsic[..])?;
+
+    let api = ClientBuilder::new()
+        .set_url(url)
+        .build()
+        .await?
+        .to_runtime_api::<pontem::RuntimeApi<pontem::DefaultConfig>>();
+
+    let hash = api.client.rpc().submit_extrinsic(extrinsic).await?;
+    Ok(hash.to_string())
+}
+
+/// Encode the call using the runtime metadata
+fn encode_call<C: subxt::Call>(metadata: &Metadata, call: C) -> Result<Encoded> {
+    Ok(metadata.pallet(C::PALLET)?.encode_call_to(call)?)
+}
+
+/// Parameters for offline signing
+struct SignParams {
+    /// Account nonce.
+    nonce: u32,
+    /// Genesis hash of the chain.
+    genesis_hash: H256,
+    /// Runtime spec and transaction versions.
+    runtime_version: RuntimeVersion,
+}
+
+impl SignParams {
+    pub fn new(
+        nonce: u32,
+        genesis_hash: &str,
+        spec_version: u32,
+        transaction_version: u32,
+    ) -> Result<SignParams> {
+        let genesis_hash = hex::decode(genesis_hash.trim_start_matches("0x"))?;
+        ensure!(
+            genesis_hash.len() == 32,
+            "Genesis hash must be 32 bytes long. Actual: {}",
+            genesis_hash.len()
+        );
+
+        Ok(SignParams {
+            nonce,
+            genesis_hash: H256::from_slice(&genesis_hash),
+            runtime_version: RuntimeVersion {
+                spec_version,
+                transaction_version,
+                other: Default::default(),
+            },
+        })
+    }
+}
+
 /// Converting a test account alias or ss58 address into a keyring
 fn test_keyring_from_str(signer: &str) -> Result<AccountKeyring> {
     let signer_lowercase = signer.strip_prefix("//").unwrap_or(signer).to_lowercase();
@@ -418,10 +610,13 @@ impl Context {
 
 #[cfg(test)]
 mod tests {
+    use codec::{Decode, Encode};
     use log::debug;
+    use subxt::UncheckedExtrinsic;
+    use crate::pontem::DefaultConfig;
     use crate::{
         test_keyring_from_str, tx_mvm_publish_module_dev, tx_mvm_execute_dev,
-        tx_mvm_publish_package_dev, version, tx_mvm_publish_module,
+        tx_mvm_publish_package_dev, version, tx_mvm_publish_module, tx_mvm_sign_dev, SignParams,
     };
 
     #[test]
@@ -481,6 +676,42 @@ mod tests {
         debug!("{}", version());
     }
 
+    #[test]
+    fn test_sign_params() {
+        let hash = "0x3fd7b9eb6a00376e5be61f01abb429ffb0b104be05eaff4d458da48fcd425baf";
+        assert!(SignParams::new(0, hash, 1, 1).is_ok());
+        assert!(SignParams::new(0, "0x3fd7", 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_tx_mvm_sign_dev() {
+        let hash = "0x3fd7b9eb6a00376e5be61f01abb429ffb0b104be05eaff4d458da48fcd425baf";
+        let dir = std::env::temp_dir().join("pontemapi_test_tx_mvm_sign_dev");
+        std::fs::create_dir_all(&dir).unwrap();
+        let tx_path = dir.join("main.mvt");
+        std::fs::write(&tx_path, [1, 2, 3, 4]).unwrap();
+        let tx_path = tx_path.to_str().unwrap();
+
+        let sign = |nonce| {
+            let signed = tx_mvm_sign_dev(tx_path, 100, "//Alice", nonce, hash, 1, 1).unwrap();
+            assert!(signed.starts_with("0x"));
+            let bytes = hex::decode(signed.trim_start_matches("0x")).unwrap();
+            UncheckedExtrinsic::<DefaultConfig>::decode(&mut &bytes[..]).unwrap()
+        };
+        // sr25519 signatures are randomized, everything else must be the same.
+        let unsigned_parts = |ext: UncheckedExtrinsic<DefaultConfig>| {
+            let (address, _, extra) = ext.signature.expect("Signed extrinsic");
+            (address, extra, ext.function).encode()
+        };
+
+        let first = unsigned_parts(sign(3));
+        assert_eq!(first, unsigned_parts(sign(3)));
+        assert_ne!(first, unsigned_parts(sign(4)));
+        assert!(tx_mvm_sign_dev(tx_path, 100, "//Alice", 3, "0x3fd7", 1, 1).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     #[ignore]
     fn test_key_pair() {
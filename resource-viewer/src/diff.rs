@@ -0,0 +1,254 @@
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue};
+
+/// Field-level difference of a resource between two blocks.
+#[derive(Debug, Serialize)]
+pub struct ResourceDiff {
+    /// Resource type.
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// Block number of the original state.
+    pub from_height: String,
+    /// Block number of the changed state.
+    pub to_height: String,
+    /// Changed fields.
+    pub changes: Vec<FieldChange>,
+}
+
+/// Change of a single field.
+/// `path` is empty if the whole resource was added or removed.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct FieldChange {
+    /// Path to the field, e.g. `info.items[2]`.
+    pub path: String,
+    #[serde(flatten)]
+    pub change: Change,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    Added { to: String },
+    Removed { from: String },
+    Modified { from: String, to: String },
+}
+
+impl ResourceDiff {
+    /// Compares two states of the resource. `None` means that the resource does not exist.
+    pub fn new(
+        type_: String,
+        (from_height, from): (String, Option<&AnnotatedMoveStruct>),
+        (to_height, to): (String, Option<&AnnotatedMoveStruct>),
+    ) -> ResourceDiff {
+        let mut changes = Vec::new();
+        match (from, to) {
+            (Some(from), Some(to)) => diff_struct("", from, to, &mut changes),
+            (None, Some(to)) => changes.push(FieldChange {
+                path: String::new(),
+                change: Change::Added { to: to.to_string() },
+            }),
+            (Some(from), None) => changes.push(FieldChange {
+                path: String::new(),
+                change: Change::Removed {
+                    from: from.to_string(),
+                },
+            }),
+            (None, None) => {}
+        }
+
+        ResourceDiff {
+            type_,
+            from_height,
+            to_height,
+            changes,
+        }
+    }
+}
+
+impl Display for ResourceDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} [{} -> {}]",
+            self.type_, self.from_height, self.to_height
+        )?;
+        if self.changes.is_empty() {
+            return write!(f, "  No changes");
+        }
+
+        for (idx, change) in self.changes.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            let path = if change.path.is_empty() {
+                "<resource>"
+            } else {
+                &change.path
+            };
+            match &change.change {
+                Change::Added { to } => write!(f, "  + {}: {}", path, to)?,
+                Change::Removed { from } => write!(f, "  - {}: {}", path, from)?,
+                Change::Modified { from, to } => write!(f, "  ~ {}: {} -> {}", path, from, to)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn diff_struct(
+    path: &str,
+    from: &AnnotatedMoveStruct,
+    to: &AnnotatedMoveStruct,
+    changes: &mut Vec<FieldChange>,
+) {
+    if from.type_ != to.type_ {
+        changes.push(modified(path, from.to_string(), to.to_string()));
+        return;
+    }
+
+    for ((name, from), (_, to)) in from.value.iter().zip(&to.value) {
+        let path = if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", path, name)
+        };
+        diff_value(&path, from, to, changes);
+    }
+}
+
+fn diff_value(
+    path: &str,
+    from: &AnnotatedMoveValue,
+    to: &AnnotatedMoveValue,
+    changes: &mut Vec<FieldChange>,
+) {
+    match (from, to) {
+        (AnnotatedMoveValue::Struct(from), AnnotatedMoveValue::Struct(to)) => {
+            diff_struct(path, from, to, changes)
+        }
+        (AnnotatedMoveValue::Vector(from_tp, from), AnnotatedMoveValue::Vector(to_tp, to))
+            if from_tp == to_tp =>
+        {
+            for (idx, (from, to)) in from.iter().zip(to).enumerate() {
+                diff_value(&format!("{}[{}]", path, idx), from, to, changes);
+            }
+            for (idx, to) in to.iter().enumerate().skip(from.len()) {
+                changes.push(FieldChange {
+                    path: format!("{}[{}]", path, idx),
+                    change: Change::Added { to: to.to_string() },
+                });
+            }
+            for (idx, from) in from.iter().enumerate().skip(to.len()) {
+                changes.push(FieldChange {
+                    path: format!("{}[{}]", path, idx),
+                    change: Change::Removed {
+                        from: from.to_string(),
+                    },
+                });
+            }
+        }
+        (from, to) => {
+            let (from, to) = (from.to_string(), to.to_string());
+            if from != to {
+                changes.push(modified(path, from, to));
+            }
+        }
+    }
+}
+
+fn modified(path: &str, from: String, to: String) -> FieldChange {
+    FieldChange {
+        path: path.to_string(),
+        change: Change::Modified { from, to },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use move_binary_format::file_format::AbilitySet;
+    use move_core_types::account_address::AccountAddress;
+    use move_core_types::identifier::Identifier;
+    use move_core_types::language_storage::{StructTag, TypeTag};
+    use move_resource_viewer::{AnnotatedMoveStruct, AnnotatedMoveValue};
+
+    use super::{Change, FieldChange, ResourceDiff};
+
+    fn store(val: u64, items: Vec<u64>) -> AnnotatedMoveStruct {
+        AnnotatedMoveStruct {
+            abilities: AbilitySet::EMPTY,
+            type_: StructTag {
+                address: AccountAddress::from_hex_literal("0x1").unwrap(),
+                module: Identifier::new("Store").unwrap(),
+                name: Identifier::new("Store").unwrap(),
+                type_params: vec![],
+            },
+            value: vec![
+                (
+                    Identifier::new("val").unwrap(),
+                    AnnotatedMoveValue::U64(val),
+                ),
+                (
+                    Identifier::new("items").unwrap(),
+                    AnnotatedMoveValue::Vector(
+                        TypeTag::U64,
+                        items.into_iter().map(AnnotatedMoveValue::U64).collect(),
+                    ),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let from = store(1, vec![1, 2]);
+        let to = store(2, vec![1, 3, 4]);
+
+        let diff = ResourceDiff::new(
+            "0x1::Store::Store".to_string(),
+            ("1".to_string(), Some(&from)),
+            ("2".to_string(), Some(&to)),
+        );
+        assert_eq!(
+            diff.changes,
+            vec![
+                FieldChange {
+                    path: "val".to_string(),
+                    change: Change::Modified {
+                        from: "1".to_string(),
+                        to: "2".to_string()
+                    },
+                },
+                FieldChange {
+                    path: "items[1]".to_string(),
+                    change: Change::Modified {
+                        from: "2".to_string(),
+                        to: "3".to_string()
+                    },
+                },
+                FieldChange {
+                    path: "items[2]".to_string(),
+                    change: Change::Added {
+                        to: "4".to_string()
+                    },
+                },
+            ]
+        );
+
+        let diff = ResourceDiff::new(
+            "0x1::Store::Store".to_string(),
+            ("1".to_string(), Some(&from)),
+            ("2".to_string(), Some(&from)),
+        );
+        assert!(diff.changes.is_empty());
+
+        let diff = ResourceDiff::new(
+            "0x1::Store::Store".to_string(),
+            ("1".to_string(), None),
+            ("2".to_string(), Some(&to)),
+        );
+        assert_eq!(diff.changes.len(), 1);
+        assert_eq!(diff.changes[0].path, "");
+    }
+}
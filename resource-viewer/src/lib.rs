@@ -1 +1,3 @@
+/// Structural diff of resources.
+pub mod diff;
 pub mod ser;
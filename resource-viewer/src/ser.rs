@@ -24,6 +24,26 @@ pub struct AnnotatedMoveStructWrapper {
     #[serde(with = "AnnotatedMoveStructExt")]
     pub result: AnnotatedMoveStruct,
 }
+
+/// Annotated struct without the block number.
+#[derive(Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct AnnotatedMoveStructValue(
+    #[serde(with = "AnnotatedMoveStructExt")] pub AnnotatedMoveStruct,
+);
+
+/// Annotated value with its type.
+#[derive(Serialize)]
+#[cfg_attr(feature = "json-schema", derive(JsonSchema))]
+pub struct AnnotatedMoveValueWrapper {
+    /// Value type
+    #[serde(rename = "type")]
+    pub tp: String,
+
+    #[serde(with = "AnnotatedMoveValueExt")]
+    pub value: AnnotatedMoveValue,
+}
+
 #[derive(Serialize)]
 #[cfg_attr(feature = "json-schema", derive(JsonSchema))]
 #[serde(remote = "move_resource_viewer::AnnotatedMoveStruct")]